@@ -64,6 +64,32 @@ struct Args {
         default_value = "2.0,0.0,0.0"
     )]
     offset: Vec<f32>,
+
+    /// The inclusion mode of the selection.
+    #[arg(long, value_enum, default_value_t = Inclusion::Center)]
+    inclusion: Inclusion,
+
+    /// The number of standard deviations of the Gaussians for the inclusion mode.
+    #[arg(long, default_value = "3.0")]
+    sigma: f32,
+}
+
+/// The inclusion mode argument.
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+enum Inclusion {
+    Center,
+    Contained,
+    Overlap,
+}
+
+impl From<Inclusion> for gs::InclusionMode {
+    fn from(inclusion: Inclusion) -> Self {
+        match inclusion {
+            Inclusion::Center => gs::InclusionMode::Center,
+            Inclusion::Contained => gs::InclusionMode::Contained,
+            Inclusion::Overlap => gs::InclusionMode::Overlap,
+        }
+    }
 }
 
 type GaussianPod = GaussianPodWithShSingleCov3dSingleConfigs;
@@ -79,6 +105,8 @@ async fn main() {
     let radii = Vec3::from_slice(&args.scale);
     let repeat = args.repeat;
     let offset = Vec3::from_slice(&args.offset);
+    let inclusion = args.inclusion.into();
+    let sigma = args.sigma;
 
     log::debug!("Creating wgpu instance");
     let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
@@ -132,12 +160,23 @@ async fn main() {
         })
         .collect::<Vec<_>>();
 
+    log::debug!("Creating inclusion buffer");
+    let inclusion_buffer = gs::InclusionBuffer::new(&device);
+    inclusion_buffer.update(&queue, inclusion, sigma);
+
     log::debug!("Creating sphere selection bind groups");
     let sphere_selection_bind_groups = sphere_selection_buffers
         .iter()
         .map(|buffer| {
             selection_bundle.bundles[0]
-                .create_bind_group(&device, 1, [buffer as &dyn gs::core::BufferWrapper])
+                .create_bind_group(
+                    &device,
+                    1,
+                    [
+                        buffer as &dyn gs::core::BufferWrapper,
+                        &inclusion_buffer as &dyn gs::core::BufferWrapper,
+                    ],
+                )
                 .expect("bind group")
         })
         .collect::<Vec<_>>();
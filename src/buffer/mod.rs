@@ -1,3 +1,9 @@
+mod outlier;
+mod pick;
 mod selection;
+mod visibility;
 
+pub use outlier::*;
+pub use pick::*;
 pub use selection::*;
+pub use visibility::*;
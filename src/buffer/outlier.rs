@@ -0,0 +1,255 @@
+use crate::{Error, core::BufferWrapper};
+
+/// The POD representation of a statistical outlier filter.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct OutlierPod {
+    /// The size of the cells of the spatial hash grid in world space.
+    cell_size: f32,
+    /// The number of nearest neighbors.
+    k: u32,
+    /// The number of standard deviations above the global mean distance for a Gaussian to be an
+    /// outlier.
+    std_dev_multiplier: f32,
+    /// The maximum search radius in cells.
+    max_search_radius: u32,
+}
+
+impl OutlierPod {
+    /// The maximum number of nearest neighbors.
+    pub const MAX_K: u32 = 32;
+
+    /// The default maximum search radius in cells.
+    pub const DEFAULT_MAX_SEARCH_RADIUS: u32 = 8;
+
+    /// Create a new statistical outlier filter.
+    ///
+    /// `cell_size` should be around the distance to the k-th nearest neighbor of a typical
+    /// Gaussian, so that most searches end at the neighboring cells.
+    ///
+    /// The neighbors are searched in growing shells of cells until the k nearest neighbors are
+    /// found, so `max_search_radius` in cells bounds the search of isolated Gaussians. The
+    /// distances beyond `max_search_radius * cell_size` are counted as at that distance, see
+    /// [`OutlierPod::DEFAULT_MAX_SEARCH_RADIUS`].
+    ///
+    /// Returns an error if `cell_size` is not positive and finite, `k` is not between 1 and
+    /// [`OutlierPod::MAX_K`], `std_dev_multiplier` is not finite, or `max_search_radius` is 0.
+    pub fn new(
+        cell_size: f32,
+        k: u32,
+        std_dev_multiplier: f32,
+        max_search_radius: u32,
+    ) -> Result<Self, Error> {
+        if cell_size <= 0.0 || !cell_size.is_finite() {
+            return Err(Error::InvalidCellSize { cell_size });
+        }
+
+        if k == 0 || k > Self::MAX_K {
+            return Err(Error::InvalidNeighborCount {
+                k,
+                max: Self::MAX_K,
+            });
+        }
+
+        if !std_dev_multiplier.is_finite() {
+            return Err(Error::InvalidStdDevMultiplier { std_dev_multiplier });
+        }
+
+        if max_search_radius == 0 {
+            return Err(Error::InvalidMaxSearchRadius { max_search_radius });
+        }
+
+        Ok(Self {
+            cell_size,
+            k,
+            std_dev_multiplier,
+            max_search_radius,
+        })
+    }
+
+    /// Get the size of the cells of the spatial hash grid in world space.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Get the number of nearest neighbors.
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// Get the number of standard deviations above the global mean distance for a Gaussian to be
+    /// an outlier.
+    pub fn std_dev_multiplier(&self) -> f32 {
+        self.std_dev_multiplier
+    }
+
+    /// Get the maximum search radius in cells.
+    pub fn max_search_radius(&self) -> u32 {
+        self.max_search_radius
+    }
+}
+
+/// The statistical outlier filter uniform buffer.
+///
+/// This is used by both [`OutlierBundle`](crate::OutlierBundle) and
+/// [`ops::outlier`](crate::ops::outlier).
+#[derive(Debug, Clone)]
+pub struct OutlierBuffer(wgpu::Buffer);
+
+impl OutlierBuffer {
+    /// Create a new outlier buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Outlier Buffer"),
+            size: std::mem::size_of::<OutlierPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the outlier buffer.
+    pub fn update(&self, queue: &wgpu::Queue, outlier: &OutlierPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(outlier));
+    }
+}
+
+impl BufferWrapper for OutlierBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The spatial hash bucket storage buffer of the statistical outlier filter.
+///
+/// This is owned by [`OutlierBundle`](crate::OutlierBundle).
+#[derive(Debug, Clone)]
+pub struct OutlierBucketsBuffer(wgpu::Buffer);
+
+impl OutlierBucketsBuffer {
+    /// Create a new outlier buckets buffer with `bucket_count` buckets.
+    pub fn new(device: &wgpu::Device, bucket_count: u32) -> Self {
+        // A runtime sized array must have space for at least one element to be bound.
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Outlier Buckets Buffer"),
+            size: (bucket_count.max(1) as usize * std::mem::size_of::<u32>())
+                as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Get the number of buckets.
+    pub fn bucket_count(&self) -> u32 {
+        (self.0.size() / std::mem::size_of::<u32>() as wgpu::BufferAddress) as u32
+    }
+}
+
+impl BufferWrapper for OutlierBucketsBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The mean nearest neighbor distance storage buffer of the statistical outlier filter.
+///
+/// The buffer has the global statistics of the distances followed by an entry for each Gaussian,
+/// so it has to be recreated when the number of Gaussians changes.
+#[derive(Debug, Clone)]
+pub struct OutlierDistancesBuffer(wgpu::Buffer);
+
+impl OutlierDistancesBuffer {
+    /// The size of the global statistics.
+    pub const STATS_SIZE: wgpu::BufferAddress =
+        4 * std::mem::size_of::<u32>() as wgpu::BufferAddress;
+
+    /// Create a new outlier distances buffer for `gaussian_count` Gaussians.
+    pub fn new(device: &wgpu::Device, gaussian_count: u32) -> Self {
+        // A runtime sized array must have space for at least one element to be bound.
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Outlier Distances Buffer"),
+            size: Self::STATS_SIZE
+                + (gaussian_count.max(1) as usize * 2 * std::mem::size_of::<u32>())
+                    as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+}
+
+impl BufferWrapper for OutlierDistancesBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outlier_pod_should_match_wgsl_layout() {
+        assert_eq!(std::mem::size_of::<OutlierPod>(), 16);
+        assert_eq!(std::mem::offset_of!(OutlierPod, cell_size), 0);
+        assert_eq!(std::mem::offset_of!(OutlierPod, k), 4);
+        assert_eq!(std::mem::offset_of!(OutlierPod, std_dev_multiplier), 8);
+        assert_eq!(std::mem::offset_of!(OutlierPod, max_search_radius), 12);
+    }
+
+    #[test]
+    fn test_outlier_pod_new_when_valid_should_be_ok() {
+        let outlier = OutlierPod::new(0.5, 8, 2.0, OutlierPod::DEFAULT_MAX_SEARCH_RADIUS).unwrap();
+        assert_eq!(outlier.cell_size(), 0.5);
+        assert_eq!(outlier.k(), 8);
+        assert_eq!(outlier.std_dev_multiplier(), 2.0);
+        assert_eq!(
+            outlier.max_search_radius(),
+            OutlierPod::DEFAULT_MAX_SEARCH_RADIUS
+        );
+    }
+
+    #[test]
+    fn test_outlier_pod_new_when_cell_size_is_invalid_should_return_error() {
+        for cell_size in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                OutlierPod::new(cell_size, 8, 2.0, 8),
+                Err(Error::InvalidCellSize { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_outlier_pod_new_when_k_is_invalid_should_return_error() {
+        for k in [0, OutlierPod::MAX_K + 1] {
+            assert!(matches!(
+                OutlierPod::new(0.5, k, 2.0, 8),
+                Err(Error::InvalidNeighborCount { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_outlier_pod_new_when_std_dev_multiplier_is_invalid_should_return_error() {
+        for std_dev_multiplier in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert!(matches!(
+                OutlierPod::new(0.5, 8, std_dev_multiplier, 8),
+                Err(Error::InvalidStdDevMultiplier { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_outlier_pod_new_when_max_search_radius_is_zero_should_return_error() {
+        assert!(matches!(
+            OutlierPod::new(0.5, 8, 2.0, 0),
+            Err(Error::InvalidMaxSearchRadius {
+                max_search_radius: 0
+            })
+        ));
+    }
+}
@@ -0,0 +1,162 @@
+use glam::*;
+use wgpu::util::DeviceExt;
+
+use crate::{
+    Error,
+    core::{BufferWrapper, DownloadableBufferWrapper},
+};
+
+/// The POD representation of a ray for picking.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct RayPickPod {
+    /// The origin of the ray in world space.
+    pub origin: Vec3,
+    /// The number of standard deviations of the ellipsoid of the Gaussians, i.e. the k in k-sigma.
+    pub sigma: f32,
+    /// The normalized direction of the ray in world space.
+    pub dir: Vec3,
+    _padding: f32,
+}
+
+impl RayPickPod {
+    /// Create a new ray for picking.
+    ///
+    /// `dir` is normalized.
+    pub fn new(origin: Vec3, dir: Vec3, sigma: f32) -> Self {
+        Self {
+            origin,
+            sigma,
+            dir: dir.normalize(),
+            _padding: 0.0,
+        }
+    }
+}
+
+/// The ray uniform buffer for picking.
+#[derive(Debug, Clone)]
+pub struct RayPickBuffer(wgpu::Buffer);
+
+impl RayPickBuffer {
+    /// Create a new ray pick buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Ray Pick Buffer"),
+            size: std::mem::size_of::<RayPickPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the ray pick buffer.
+    pub fn update(&self, queue: &wgpu::Queue, ray: &RayPickPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(ray));
+    }
+
+    /// Update the ray pick buffer with the origin, direction, and number of standard deviations.
+    pub fn update_with_origin_dir_sigma(
+        &self,
+        queue: &wgpu::Queue,
+        origin: Vec3,
+        dir: Vec3,
+        sigma: f32,
+    ) {
+        self.update(queue, &RayPickPod::new(origin, dir, sigma));
+    }
+}
+
+impl BufferWrapper for RayPickBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The result of a ray pick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayPickResult {
+    /// The index of the picked Gaussian.
+    pub index: u32,
+    /// The distance from the origin of the ray to the hit on the ellipsoid of the Gaussian.
+    pub distance: f32,
+}
+
+/// The ray pick result storage buffer.
+///
+/// The buffer contains the bits of the hit distance followed by the index of the Gaussian, both
+/// are [`u32::MAX`] when no Gaussian is hit.
+#[derive(Debug, Clone)]
+pub struct RayPickResultBuffer(wgpu::Buffer);
+
+impl RayPickResultBuffer {
+    /// The value of the buffer when no Gaussian is hit.
+    pub const NONE: [u32; 2] = [u32::MAX; 2];
+
+    /// Create a new ray pick result buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Ray Pick Result Buffer"),
+            contents: bytemuck::cast_slice(&Self::NONE),
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self(buffer)
+    }
+
+    /// Download the ray pick result.
+    ///
+    /// Returns [`None`] if no Gaussian is hit.
+    pub async fn download_result(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Result<Option<RayPickResult>, Error> {
+        let data: Vec<u32> = self.download(device, queue).await?;
+
+        Ok(Self::decode(&data))
+    }
+
+    /// Decode the ray pick result from the downloaded data.
+    fn decode(data: &[u32]) -> Option<RayPickResult> {
+        match data[..] {
+            [distance, index] if index != u32::MAX => Some(RayPickResult {
+                index,
+                distance: f32::from_bits(distance),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl BufferWrapper for RayPickResultBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray_pick_result_buffer_decode_when_none_should_return_none() {
+        assert_eq!(
+            RayPickResultBuffer::decode(&RayPickResultBuffer::NONE),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ray_pick_result_buffer_decode_when_hit_should_return_result() {
+        assert_eq!(
+            RayPickResultBuffer::decode(&[2.5f32.to_bits(), 7]),
+            Some(RayPickResult {
+                index: 7,
+                distance: 2.5,
+            })
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use glam::*;
 use wgpu::util::DeviceExt;
 
-use crate::core::BufferWrapper;
+use crate::{
+    Error,
+    core::{BufferWrapper, GaussianPod, GaussianShConfig, GaussianShNoneConfig},
+};
 
 /// The selection storage buffer for storing selected Gaussians as a bitvec.
 #[derive(Debug, Clone)]
@@ -64,7 +67,11 @@ impl BufferWrapper for SelectionOpBuffer {
 
 /// An inverse transform uniform buffer for selection operations.
 ///
-/// This is the base for [`selection::sphere`], [`selection::box_`], and [`selection::cone`].
+/// This is the base for [`ops::sphere`](crate::ops::sphere), [`ops::box_`](crate::ops::box_),
+/// [`ops::cone`](crate::ops::cone), [`ops::cylinder`](crate::ops::cylinder), and
+/// [`ops::mesh`](crate::ops::mesh). [`ops::capsule`](crate::ops::capsule) is not based on it,
+/// since a capsule with a non-uniform scale is not a capsule, so it has its own
+/// [`CapsuleSelectionPod`].
 #[derive(Debug, Clone)]
 pub struct InvTransformBuffer(wgpu::Buffer);
 
@@ -126,3 +133,1792 @@ impl BufferWrapper for SphereSelectionBuffer {
         self.0.buffer()
     }
 }
+
+/// The box selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct BoxSelectionBuffer(InvTransformBuffer);
+
+impl BoxSelectionBuffer {
+    /// Create a new box selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self(InvTransformBuffer::new(device))
+    }
+
+    /// Update the box selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, inv_transform: Mat4) {
+        self.0.update(queue, inv_transform);
+    }
+
+    /// Update the box selection buffer with the position, rotation, and scale.
+    ///
+    /// The scale is the full size of the box along each of its local axes.
+    pub fn update_with_pos_rot_scale(
+        &self,
+        queue: &wgpu::Queue,
+        pos: Vec3,
+        rot: Quat,
+        scale: Vec3,
+    ) {
+        let inv_transform = Mat4::from_scale_rotation_translation(scale, rot, pos).inverse();
+        self.update(queue, inv_transform);
+    }
+}
+
+impl BufferWrapper for BoxSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        self.0.buffer()
+    }
+}
+
+/// The cone selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct ConeSelectionBuffer(InvTransformBuffer);
+
+impl ConeSelectionBuffer {
+    /// Create a new cone selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self(InvTransformBuffer::new(device))
+    }
+
+    /// Update the cone selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, inv_transform: Mat4) {
+        self.0.update(queue, inv_transform);
+    }
+
+    /// Update the cone selection buffer with the apex, axis rotation, half angle, and height.
+    ///
+    /// The axis of the cone is +Z rotated by `rot`, and `half_angle` is in radians measured from
+    /// the axis.
+    ///
+    /// Returns [`Error::InvalidHalfAngle`] if `half_angle` is not between 0 and a right angle
+    /// exclusive, or [`Error::InvalidHeight`] if `height` is not positive and finite, since the
+    /// cone would have no inverse transform.
+    pub fn update_with_apex_rot_half_angle_height(
+        &self,
+        queue: &wgpu::Queue,
+        apex: Vec3,
+        rot: Quat,
+        half_angle: f32,
+        height: f32,
+    ) -> Result<(), Error> {
+        if !(half_angle > 0.0 && half_angle < std::f32::consts::FRAC_PI_2) {
+            return Err(Error::InvalidHalfAngle { half_angle });
+        }
+
+        if height <= 0.0 || !height.is_finite() {
+            return Err(Error::InvalidHeight { height });
+        }
+
+        let radius = half_angle.tan() * height;
+        let inv_transform =
+            Mat4::from_scale_rotation_translation(Vec3::new(radius, radius, height), rot, apex)
+                .inverse();
+        self.update(queue, inv_transform);
+
+        Ok(())
+    }
+}
+
+impl BufferWrapper for ConeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        self.0.buffer()
+    }
+}
+
+/// The cylinder selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct CylinderSelectionBuffer(InvTransformBuffer);
+
+impl CylinderSelectionBuffer {
+    /// Create a new cylinder selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self(InvTransformBuffer::new(device))
+    }
+
+    /// Update the cylinder selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, inv_transform: Mat4) {
+        self.0.update(queue, inv_transform);
+    }
+
+    /// Update the cylinder selection buffer with the position, rotation, radius, and height.
+    ///
+    /// The axis of the cylinder is Z rotated by `rot`, and `pos` is the center of the cylinder.
+    pub fn update_with_pos_rot_radius_height(
+        &self,
+        queue: &wgpu::Queue,
+        pos: Vec3,
+        rot: Quat,
+        radius: f32,
+        height: f32,
+    ) {
+        let inv_transform = Mat4::from_scale_rotation_translation(
+            Vec3::new(radius, radius, height * 0.5),
+            rot,
+            pos,
+        )
+        .inverse();
+        self.update(queue, inv_transform);
+    }
+}
+
+impl BufferWrapper for CylinderSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        self.0.buffer()
+    }
+}
+
+/// The POD representation of a capsule selection.
+///
+/// The capsule is the set of points within `radius` of the segment from `-half_length` to
+/// `half_length` on the Z axis, in the space transformed by `inv_transform`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CapsuleSelectionPod {
+    /// The inverse transform of the capsule, which should be rigid.
+    pub inv_transform: Mat4,
+    /// The radius of the capsule.
+    pub radius: f32,
+    /// The half length of the segment between the centers of the caps.
+    pub half_length: f32,
+    _padding: [f32; 2],
+}
+
+impl CapsuleSelectionPod {
+    /// Create a new capsule selection.
+    pub fn new(inv_transform: Mat4, radius: f32, half_length: f32) -> Self {
+        Self {
+            inv_transform,
+            radius,
+            half_length,
+            _padding: [0.0; 2],
+        }
+    }
+
+    /// Create a new capsule selection with the position, rotation, radius, and height.
+    ///
+    /// The height includes both of the hemispherical caps.
+    pub fn new_with_pos_rot_radius_height(pos: Vec3, rot: Quat, radius: f32, height: f32) -> Self {
+        let inv_transform = Mat4::from_rotation_translation(rot, pos).inverse();
+        Self::new(inv_transform, radius, (height * 0.5 - radius).max(0.0))
+    }
+}
+
+/// The capsule selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct CapsuleSelectionBuffer(wgpu::Buffer);
+
+impl CapsuleSelectionBuffer {
+    /// Create a new capsule selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Capsule Selection Buffer"),
+            size: std::mem::size_of::<CapsuleSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the capsule selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, capsule: &CapsuleSelectionPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(capsule));
+    }
+
+    /// Update the capsule selection buffer with the position, rotation, radius, and height.
+    ///
+    /// The axis of the capsule is Z rotated by `rot`, `pos` is the center of the capsule, and the
+    /// height includes both of the hemispherical caps.
+    pub fn update_with_pos_rot_radius_height(
+        &self,
+        queue: &wgpu::Queue,
+        pos: Vec3,
+        rot: Quat,
+        radius: f32,
+        height: f32,
+    ) {
+        self.update(
+            queue,
+            &CapsuleSelectionPod::new_with_pos_rot_radius_height(pos, rot, radius, height),
+        );
+    }
+}
+
+impl BufferWrapper for CapsuleSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The plane selection uniform buffer.
+///
+/// The plane is defined in world space, i.e. after the model transform is applied, by a normal
+/// and an offset along the normal from the origin.
+#[derive(Debug, Clone)]
+pub struct PlaneSelectionBuffer(wgpu::Buffer);
+
+impl PlaneSelectionBuffer {
+    /// Create a new plane selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Plane Selection Buffer"),
+            size: std::mem::size_of::<Vec4>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the plane selection buffer.
+    ///
+    /// `normal` does not have to be normalized, in which case `offset` is scaled accordingly.
+    pub fn update(&self, queue: &wgpu::Queue, normal: Vec3, offset: f32) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(&normal.extend(offset)));
+    }
+
+    /// Update the plane selection buffer with a point on the plane and the normal.
+    pub fn update_with_point_normal(&self, queue: &wgpu::Queue, point: Vec3, normal: Vec3) {
+        self.update(queue, normal, normal.dot(point));
+    }
+}
+
+impl BufferWrapper for PlaneSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The convex polytope selection storage buffer.
+///
+/// Each plane is a [`Vec4`] with the normal in `xyz` and the offset in `w`, following the same
+/// convention as [`PlaneSelectionBuffer`]. The buffer holds up to a fixed number of planes.
+#[derive(Debug, Clone)]
+pub struct ConvexPolytopeSelectionBuffer {
+    buffer: wgpu::Buffer,
+    capacity: usize,
+}
+
+impl ConvexPolytopeSelectionBuffer {
+    /// Create a new convex polytope selection buffer with the maximum number of planes.
+    ///
+    /// The buffer starts with no planes, which selects all Gaussians.
+    pub fn new(device: &wgpu::Device, capacity: usize) -> Self {
+        // A runtime sized array must have space for at least one element to be bound.
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Convex Polytope Selection Buffer"),
+            size: (std::mem::size_of::<UVec4>() + capacity.max(1) * std::mem::size_of::<Vec4>())
+                as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self { buffer, capacity }
+    }
+
+    /// Get the maximum number of planes.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Update the planes of the convex polytope selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, planes: &[Vec4]) -> Result<(), Error> {
+        check_plane_count(planes.len(), self.capacity)?;
+
+        queue.write_buffer(
+            &self.buffer,
+            0,
+            bytemuck::bytes_of(&UVec4::new(planes.len() as u32, 0, 0, 0)),
+        );
+        queue.write_buffer(
+            &self.buffer,
+            std::mem::size_of::<UVec4>() as wgpu::BufferAddress,
+            bytemuck::cast_slice(planes),
+        );
+
+        Ok(())
+    }
+}
+
+impl BufferWrapper for ConvexPolytopeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+}
+
+/// Check the number of planes does not exceed the capacity of the buffer.
+fn check_plane_count(count: usize, capacity: usize) -> Result<(), Error> {
+    if count > capacity {
+        return Err(Error::PlaneCountExceedsCapacity { count, capacity });
+    }
+
+    Ok(())
+}
+
+/// The POD representation of a frustum selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct FrustumSelectionPod {
+    /// The view projection matrix.
+    pub view_proj: Mat4,
+    /// The near distance when [`FrustumSelectionPod::depth_override`] is set.
+    pub near: f32,
+    /// The far distance when [`FrustumSelectionPod::depth_override`] is set.
+    pub far: f32,
+    /// Whether to use the near and far distances instead of the depth range of the projection.
+    pub depth_override: u32,
+    _padding: u32,
+}
+
+impl FrustumSelectionPod {
+    /// Create a new frustum selection.
+    ///
+    /// `near_far` overrides the near and far planes of the projection, which is compared against
+    /// the clip space w, i.e. the view space depth for perspective projections.
+    pub fn new(view_proj: Mat4, near_far: Option<Vec2>) -> Self {
+        Self {
+            view_proj,
+            near: near_far.map_or(0.0, |near_far| near_far.x),
+            far: near_far.map_or(0.0, |near_far| near_far.y),
+            depth_override: near_far.is_some() as u32,
+            _padding: 0,
+        }
+    }
+}
+
+/// The frustum selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct FrustumSelectionBuffer(wgpu::Buffer);
+
+impl FrustumSelectionBuffer {
+    /// Create a new frustum selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Frustum Selection Buffer"),
+            size: std::mem::size_of::<FrustumSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the frustum selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, frustum: &FrustumSelectionPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(frustum));
+    }
+
+    /// Update the frustum selection buffer with the view projection matrix and optional near and
+    /// far override.
+    pub fn update_with_view_proj(
+        &self,
+        queue: &wgpu::Queue,
+        view_proj: Mat4,
+        near_far: Option<Vec2>,
+    ) {
+        self.update(queue, &FrustumSelectionPod::new(view_proj, near_far));
+    }
+}
+
+impl BufferWrapper for FrustumSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The POD representation of a screen space rectangle selection.
+///
+/// The pixel coordinates have the origin at the top left corner of the viewport.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct RectSelectionPod {
+    /// The view matrix.
+    pub view: Mat4,
+    /// The projection matrix.
+    pub proj: Mat4,
+    /// The size of the viewport in pixels.
+    pub viewport_size: Vec2,
+    /// The minimum corner of the rectangle in pixels.
+    pub min: Vec2,
+    /// The maximum corner of the rectangle in pixels.
+    pub max: Vec2,
+    _padding: [f32; 2],
+}
+
+impl RectSelectionPod {
+    /// Create a new screen space rectangle selection.
+    ///
+    /// `a` and `b` are any two opposite corners of the rectangle in pixels.
+    pub fn new(view: Mat4, proj: Mat4, viewport_size: Vec2, a: Vec2, b: Vec2) -> Self {
+        Self {
+            view,
+            proj,
+            viewport_size,
+            min: a.min(b),
+            max: a.max(b),
+            _padding: [0.0; 2],
+        }
+    }
+}
+
+/// The screen space rectangle selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct RectSelectionBuffer(wgpu::Buffer);
+
+impl RectSelectionBuffer {
+    /// Create a new rectangle selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Rect Selection Buffer"),
+            size: std::mem::size_of::<RectSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the rectangle selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, rect: &RectSelectionPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(rect));
+    }
+}
+
+impl BufferWrapper for RectSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The fill rule of a polygon.
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the edges an odd number of times.
+    #[default]
+    EvenOdd = 0,
+    /// A point is inside if the polygon winds around it a non-zero number of times.
+    NonZero = 1,
+}
+
+/// The POD representation of the header of a screen space lasso selection.
+///
+/// The vertices of the polygon follow the header in [`LassoSelectionBuffer`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LassoSelectionPod {
+    /// The view matrix.
+    pub view: Mat4,
+    /// The projection matrix.
+    pub proj: Mat4,
+    /// The size of the viewport in pixels.
+    pub viewport_size: Vec2,
+    vertex_count: u32,
+    fill_rule: u32,
+}
+
+impl LassoSelectionPod {
+    /// Create a new screen space lasso selection.
+    pub fn new(view: Mat4, proj: Mat4, viewport_size: Vec2, fill_rule: FillRule) -> Self {
+        Self {
+            view,
+            proj,
+            viewport_size,
+            vertex_count: 0,
+            fill_rule: fill_rule as u32,
+        }
+    }
+
+    /// Get the number of vertices.
+    pub fn vertex_count(&self) -> u32 {
+        self.vertex_count
+    }
+}
+
+/// The screen space lasso selection storage buffer.
+///
+/// The vertices of the polygon are in pixels with the origin at the top left corner of the
+/// viewport, and the polygon is implicitly closed from the last vertex to the first.
+///
+/// The buffer grows when more vertices are added than its capacity, in which case the bind group
+/// of the [`ops::lasso`](crate::ops::lasso) operation has to be recreated.
+#[derive(Debug, Clone)]
+pub struct LassoSelectionBuffer(HeaderArrayBuffer<LassoSelectionPod, Vec2>);
+
+impl LassoSelectionBuffer {
+    /// Create a new lasso selection buffer with space for `capacity` vertices.
+    ///
+    /// The header is uploaded along with the vertices.
+    pub fn new(device: &wgpu::Device, header: LassoSelectionPod, capacity: usize) -> Self {
+        Self(HeaderArrayBuffer::new(
+            device,
+            "Lasso Selection Buffer",
+            LassoSelectionPod {
+                vertex_count: 0,
+                ..header
+            },
+            capacity,
+        ))
+    }
+
+    /// Get the vertices.
+    pub fn vertices(&self) -> &[Vec2] {
+        &self.0.elements
+    }
+
+    /// Get the number of vertices the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Update the header of the lasso selection buffer.
+    ///
+    /// The vertex count of `header` is ignored.
+    pub fn update(&mut self, queue: &wgpu::Queue, header: LassoSelectionPod) {
+        self.0.header = LassoSelectionPod {
+            vertex_count: self.0.header.vertex_count,
+            ..header
+        };
+        self.0.write_header(queue);
+    }
+
+    /// Replace the vertices of the lasso selection buffer.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn update_vertices(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        vertices: &[Vec2],
+    ) -> bool {
+        self.0.elements.clear();
+        self.push_vertices(device, queue, vertices)
+    }
+
+    /// Append vertices to the lasso selection buffer.
+    ///
+    /// Only the new vertices are uploaded unless the buffer has to grow.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn push_vertices(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        vertices: &[Vec2],
+    ) -> bool {
+        let start = self.0.elements.len();
+        self.0.elements.extend_from_slice(vertices);
+        self.0.header.vertex_count = self.0.elements.len() as u32;
+        self.0.upload(device, queue, start)
+    }
+
+    /// Remove all the vertices of the lasso selection buffer.
+    pub fn clear(&mut self, queue: &wgpu::Queue) {
+        self.0.elements.clear();
+        self.0.header.vertex_count = 0;
+        self.0.write_header(queue);
+    }
+}
+
+impl BufferWrapper for LassoSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0.buffer
+    }
+}
+
+/// The POD representation of a sample of a screen space brush stroke.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BrushSamplePod {
+    /// The position in pixels.
+    pub pos: Vec2,
+    /// The radius in pixels.
+    pub radius: f32,
+    _padding: f32,
+}
+
+impl BrushSamplePod {
+    /// Create a new brush sample.
+    pub fn new(pos: Vec2, radius: f32) -> Self {
+        Self {
+            pos,
+            radius,
+            _padding: 0.0,
+        }
+    }
+}
+
+/// The POD representation of the header of a screen space brush selection.
+///
+/// The samples of the stroke follow the header in [`BrushSelectionBuffer`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BrushSelectionPod {
+    /// The view matrix.
+    pub view: Mat4,
+    /// The projection matrix.
+    pub proj: Mat4,
+    /// The size of the viewport in pixels.
+    pub viewport_size: Vec2,
+    sample_count: u32,
+    _padding: u32,
+}
+
+impl BrushSelectionPod {
+    /// Create a new screen space brush selection.
+    pub fn new(view: Mat4, proj: Mat4, viewport_size: Vec2) -> Self {
+        Self {
+            view,
+            proj,
+            viewport_size,
+            sample_count: 0,
+            _padding: 0,
+        }
+    }
+
+    /// Get the number of samples.
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+}
+
+/// The screen space brush selection storage buffer.
+///
+/// The samples of the stroke are discs in pixels with the origin at the top left corner of the
+/// viewport, they can be appended each frame while the user is painting.
+///
+/// The buffer grows when more samples are added than its capacity, in which case the bind group
+/// of the [`ops::brush`](crate::ops::brush) operation has to be recreated.
+#[derive(Debug, Clone)]
+pub struct BrushSelectionBuffer(HeaderArrayBuffer<BrushSelectionPod, BrushSamplePod>);
+
+impl BrushSelectionBuffer {
+    /// Create a new brush selection buffer with space for `capacity` samples.
+    ///
+    /// The header is uploaded along with the samples.
+    pub fn new(device: &wgpu::Device, header: BrushSelectionPod, capacity: usize) -> Self {
+        Self(HeaderArrayBuffer::new(
+            device,
+            "Brush Selection Buffer",
+            BrushSelectionPod {
+                sample_count: 0,
+                ..header
+            },
+            capacity,
+        ))
+    }
+
+    /// Get the samples.
+    pub fn samples(&self) -> &[BrushSamplePod] {
+        &self.0.elements
+    }
+
+    /// Get the number of samples the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Update the header of the brush selection buffer.
+    ///
+    /// The sample count of `header` is ignored.
+    pub fn update(&mut self, queue: &wgpu::Queue, header: BrushSelectionPod) {
+        self.0.header = BrushSelectionPod {
+            sample_count: self.0.header.sample_count,
+            ..header
+        };
+        self.0.write_header(queue);
+    }
+
+    /// Append samples to the brush selection buffer.
+    ///
+    /// Only the new samples are uploaded unless the buffer has to grow.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn push_samples(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        samples: &[BrushSamplePod],
+    ) -> bool {
+        let start = self.0.elements.len();
+        self.0.elements.extend_from_slice(samples);
+        self.0.header.sample_count = self.0.elements.len() as u32;
+        self.0.upload(device, queue, start)
+    }
+
+    /// Remove all the samples of the brush selection buffer.
+    pub fn clear(&mut self, queue: &wgpu::Queue) {
+        self.0.elements.clear();
+        self.0.header.sample_count = 0;
+        self.0.write_header(queue);
+    }
+}
+
+impl BufferWrapper for BrushSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0.buffer
+    }
+}
+
+/// A storage buffer of a header followed by an array which grows as elements are added.
+#[derive(Debug, Clone)]
+struct HeaderArrayBuffer<H, T> {
+    label: &'static str,
+    buffer: wgpu::Buffer,
+    header: H,
+    elements: Vec<T>,
+    capacity: usize,
+}
+
+impl<H: bytemuck::Pod, T: bytemuck::Pod> HeaderArrayBuffer<H, T> {
+    /// The offset of the elements in the buffer.
+    const ELEMENTS_OFFSET: wgpu::BufferAddress = std::mem::size_of::<H>() as wgpu::BufferAddress;
+
+    /// Create a new buffer with space for `capacity` elements.
+    fn new(device: &wgpu::Device, label: &'static str, header: H, capacity: usize) -> Self {
+        // A runtime sized array must have space for at least one element to be bound.
+        let capacity = capacity.max(1);
+        let buffer = Self::create_buffer(device, label, capacity);
+
+        Self {
+            label,
+            buffer,
+            header,
+            elements: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Write the header.
+    fn write_header(&self, queue: &wgpu::Queue) {
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&self.header));
+    }
+
+    /// Write the header and the elements from `start`, growing the buffer if necessary.
+    ///
+    /// Returns `true` if the buffer has grown.
+    fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, start: usize) -> bool {
+        let grown = self.elements.len() > self.capacity;
+        let start = if grown {
+            self.capacity = self.elements.len().next_power_of_two();
+            self.buffer = Self::create_buffer(device, self.label, self.capacity);
+            0
+        } else {
+            start
+        };
+
+        self.write_header(queue);
+        queue.write_buffer(
+            &self.buffer,
+            Self::ELEMENTS_OFFSET + (start * std::mem::size_of::<T>()) as wgpu::BufferAddress,
+            bytemuck::cast_slice(&self.elements[start..]),
+        );
+
+        grown
+    }
+
+    /// Create the buffer with space for `capacity` elements.
+    fn create_buffer(device: &wgpu::Device, label: &str, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: Self::ELEMENTS_OFFSET
+                + (capacity * std::mem::size_of::<T>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+}
+
+/// The mode of including a Gaussian in a volume selection.
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InclusionMode {
+    /// Only the center of the Gaussian has to be inside the volume.
+    #[default]
+    Center = 0,
+    /// The k-sigma ellipsoid of the Gaussian has to be fully contained in the volume.
+    Contained = 1,
+    /// The k-sigma ellipsoid of the Gaussian has to overlap the volume.
+    Overlap = 2,
+}
+
+/// The POD representation of an inclusion mode.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct InclusionPod {
+    /// The [`InclusionMode`].
+    pub mode: u32,
+    /// The number of standard deviations of the ellipsoid, i.e. the k in k-sigma.
+    pub sigma: f32,
+    _padding: [u32; 2],
+}
+
+impl InclusionPod {
+    /// Create a new inclusion mode.
+    pub fn new(mode: InclusionMode, sigma: f32) -> Self {
+        Self {
+            mode: mode as u32,
+            sigma,
+            _padding: [0; 2],
+        }
+    }
+}
+
+impl Default for InclusionPod {
+    fn default() -> Self {
+        Self::new(InclusionMode::default(), 3.0)
+    }
+}
+
+/// The inclusion mode uniform buffer for volume selection operations.
+///
+/// The Gaussian covariance is scaled by the size in the Gaussian transform and transformed by the
+/// model transform. For curved surfaces, the ellipsoid is bounded by a sphere of its largest
+/// standard deviation, so the test is conservative.
+///
+/// This is used by [`ops::sphere`](crate::ops::sphere), [`ops::box_`](crate::ops::box_),
+/// [`ops::cone`](crate::ops::cone), [`ops::cylinder`](crate::ops::cylinder),
+/// [`ops::capsule`](crate::ops::capsule), [`ops::half_space`](crate::ops::half_space),
+/// [`ops::convex_polytope`](crate::ops::convex_polytope), [`ops::frustum`](crate::ops::frustum),
+/// and [`ops::depth_range`](crate::ops::depth_range).
+#[derive(Debug, Clone)]
+pub struct InclusionBuffer(wgpu::Buffer);
+
+impl InclusionBuffer {
+    /// Create a new inclusion buffer with [`InclusionMode::Center`].
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Inclusion Buffer"),
+            contents: bytemuck::bytes_of(&InclusionPod::default()),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the inclusion buffer.
+    pub fn update(&self, queue: &wgpu::Queue, mode: InclusionMode, sigma: f32) {
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&InclusionPod::new(mode, sigma)),
+        );
+    }
+}
+
+impl BufferWrapper for InclusionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The opacity range selection uniform buffer.
+///
+/// The opacity is in the range of 0 to 1, as decoded from the Gaussian.
+#[derive(Debug, Clone)]
+pub struct OpacityRangeSelectionBuffer(wgpu::Buffer);
+
+impl OpacityRangeSelectionBuffer {
+    /// Create a new opacity range selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Opacity Range Selection Buffer"),
+            size: std::mem::size_of::<Vec2>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the opacity range selection buffer with the inclusive minimum and maximum.
+    pub fn update(&self, queue: &wgpu::Queue, min: f32, max: f32) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(&Vec2::new(min, max)));
+    }
+}
+
+impl BufferWrapper for OpacityRangeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The axis scale of a Gaussian to compare in a scale range selection.
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleMetric {
+    /// The largest axis scale.
+    #[default]
+    Largest = 0,
+    /// The smallest axis scale.
+    Smallest = 1,
+    /// The mean of the axis scales.
+    Mean = 2,
+}
+
+/// The POD representation of a scale range selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ScaleRangeSelectionPod {
+    /// The inclusive minimum scale.
+    pub min: f32,
+    /// The inclusive maximum scale.
+    pub max: f32,
+    /// The [`ScaleMetric`].
+    pub metric: u32,
+    _padding: u32,
+}
+
+impl ScaleRangeSelectionPod {
+    /// Create a new scale range selection.
+    pub fn new(min: f32, max: f32, metric: ScaleMetric) -> Self {
+        Self {
+            min,
+            max,
+            metric: metric as u32,
+            _padding: 0,
+        }
+    }
+}
+
+/// The scale range selection uniform buffer.
+///
+/// The axis scales are the standard deviations along the principal axes of the Gaussian in world
+/// space, derived from its covariance, which is scaled by the size in the Gaussian transform and
+/// transformed by the model transform.
+#[derive(Debug, Clone)]
+pub struct ScaleRangeSelectionBuffer(wgpu::Buffer);
+
+impl ScaleRangeSelectionBuffer {
+    /// Create a new scale range selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Scale Range Selection Buffer"),
+            size: std::mem::size_of::<ScaleRangeSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the scale range selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, min: f32, max: f32, metric: ScaleMetric) {
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&ScaleRangeSelectionPod::new(min, max, metric)),
+        );
+    }
+}
+
+impl BufferWrapper for ScaleRangeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The anisotropy selection uniform buffer.
+///
+/// The threshold is the ratio of the largest to the smallest eigenvalue of the covariance, i.e.
+/// the square of the ratio of the largest to the smallest axis scale.
+#[derive(Debug, Clone)]
+pub struct AnisotropySelectionBuffer(wgpu::Buffer);
+
+impl AnisotropySelectionBuffer {
+    /// Create a new anisotropy selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Anisotropy Selection Buffer"),
+            size: std::mem::size_of::<f32>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the anisotropy selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, threshold: f32) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(&threshold));
+    }
+}
+
+impl BufferWrapper for AnisotropySelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The color space to compare colors in.
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// RGB, with all components in the range of 0 to 1.
+    #[default]
+    Rgb = 0,
+    /// HSV, with all components in the range of 0 to 1, and the hue wraps around.
+    Hsv = 1,
+    /// CIELAB with the D65 white point, where the distance is the CIE76 color difference.
+    Lab = 2,
+}
+
+/// The POD representation of a color range selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ColorRangeSelectionPod {
+    /// The reference color in RGB.
+    pub reference: Vec3,
+    /// The maximum distance from the reference color in the color space.
+    pub threshold: f32,
+    /// The [`ColorSpace`].
+    pub space: u32,
+    _padding: [u32; 3],
+}
+
+impl ColorRangeSelectionPod {
+    /// Create a new color range selection.
+    pub fn new(reference: Vec3, threshold: f32, space: ColorSpace) -> Self {
+        Self {
+            reference,
+            threshold,
+            space: space as u32,
+            _padding: [0; 3],
+        }
+    }
+}
+
+/// The color range selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct ColorRangeSelectionBuffer(wgpu::Buffer);
+
+impl ColorRangeSelectionBuffer {
+    /// Create a new color range selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Color Range Selection Buffer"),
+            size: std::mem::size_of::<ColorRangeSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the color range selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, reference: Vec3, threshold: f32, space: ColorSpace) {
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&ColorRangeSelectionPod::new(reference, threshold, space)),
+        );
+    }
+}
+
+impl BufferWrapper for ColorRangeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The view to evaluate the view dependent color of the Gaussians from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ViewDirection {
+    /// The direction is from the camera position in world space to each Gaussian.
+    FromCamera(Vec3),
+    /// The same direction in world space for all Gaussians.
+    Fixed(Vec3),
+}
+
+/// The POD representation of a view dependent color range selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ViewColorRangeSelectionPod {
+    /// The color range.
+    pub range: ColorRangeSelectionPod,
+    /// The camera position or the direction, depending on the [`ViewDirection`].
+    pub view: Vec3,
+    /// The mode of the [`ViewDirection`].
+    pub view_mode: u32,
+    /// The maximum degree of the spherical harmonics to evaluate.
+    ///
+    /// This is clamped to the degree stored by the Gaussians when the buffer is updated.
+    pub sh_deg: u32,
+    _padding: [u32; 3],
+}
+
+impl ViewColorRangeSelectionPod {
+    /// The maximum degree of the spherical harmonics stored by the Gaussians.
+    pub const MAX_SH_DEG: u32 = 3;
+
+    /// Create a new view dependent color range selection.
+    pub fn new(range: ColorRangeSelectionPod, view: ViewDirection, sh_deg: u32) -> Self {
+        let (view, view_mode) = match view {
+            ViewDirection::FromCamera(pos) => (pos, 0),
+            ViewDirection::Fixed(dir) => (dir, 1),
+        };
+
+        Self {
+            range,
+            view,
+            view_mode,
+            sh_deg,
+            _padding: [0; 3],
+        }
+    }
+}
+
+/// The view dependent color range selection uniform buffer.
+///
+/// The spherical harmonics are evaluated in model space, the direction is transformed by the
+/// inverse rotation of the model transform.
+#[derive(Debug, Clone)]
+pub struct ViewColorRangeSelectionBuffer(wgpu::Buffer);
+
+impl ViewColorRangeSelectionBuffer {
+    /// Create a new view dependent color range selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("View Color Range Selection Buffer"),
+            size: std::mem::size_of::<ViewColorRangeSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the view dependent color range selection buffer.
+    ///
+    /// The degree of the spherical harmonics is clamped to the degree stored by `G`, so that
+    /// coefficients which are not stored are never read.
+    pub fn update<G: GaussianPod>(
+        &self,
+        queue: &wgpu::Queue,
+        view_color_range: &ViewColorRangeSelectionPod,
+    ) {
+        let max_sh_deg = if G::ShConfig::FEATURE == GaussianShNoneConfig::FEATURE {
+            0
+        } else {
+            ViewColorRangeSelectionPod::MAX_SH_DEG
+        };
+
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&ViewColorRangeSelectionPod {
+                sh_deg: view_color_range.sh_deg.min(max_sh_deg),
+                ..*view_color_range
+            }),
+        );
+    }
+}
+
+impl BufferWrapper for ViewColorRangeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The indices selection storage buffer.
+///
+/// The indices are sorted and deduplicated before being uploaded.
+///
+/// The buffer grows when more indices are set than its capacity, in which case the bind group
+/// of the [`ops::indices`](crate::ops::indices) operation has to be recreated.
+#[derive(Debug, Clone)]
+pub struct IndicesSelectionBuffer(HeaderArrayBuffer<u32, u32>);
+
+impl IndicesSelectionBuffer {
+    /// Create a new indices selection buffer with space for `capacity` indices.
+    pub fn new(device: &wgpu::Device, capacity: usize) -> Self {
+        Self(HeaderArrayBuffer::new(
+            device,
+            "Indices Selection Buffer",
+            0,
+            capacity,
+        ))
+    }
+
+    /// Get the sorted and deduplicated indices.
+    pub fn indices(&self) -> &[u32] {
+        &self.0.elements
+    }
+
+    /// Get the number of indices the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Update the indices of the indices selection buffer.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        indices: impl IntoIterator<Item = u32>,
+    ) -> bool {
+        self.0.elements.clear();
+        self.0.elements.extend(indices);
+        self.0.elements.sort_unstable();
+        self.0.elements.dedup();
+        self.0.header = self.0.elements.len() as u32;
+        self.0.upload(device, queue, 0)
+    }
+}
+
+impl BufferWrapper for IndicesSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0.buffer
+    }
+}
+
+/// The index range selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct IndexRangeSelectionBuffer(wgpu::Buffer);
+
+impl IndexRangeSelectionBuffer {
+    /// Create a new index range selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Index Range Selection Buffer"),
+            size: std::mem::size_of::<UVec2>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the index range selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, range: std::ops::Range<u32>) {
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&UVec2::new(range.start, range.end)),
+        );
+    }
+}
+
+impl BufferWrapper for IndexRangeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The POD representation of a random selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct RandomSelectionPod {
+    /// The fraction of the Gaussians to select, in the range of 0 to 1.
+    pub fraction: f32,
+    /// The seed of the hash.
+    pub seed: u32,
+}
+
+/// The random selection uniform buffer.
+///
+/// The same fraction and seed always select the same Gaussians, and the selection with a larger
+/// fraction is a superset of that with a smaller fraction for the same seed.
+#[derive(Debug, Clone)]
+pub struct RandomSelectionBuffer(wgpu::Buffer);
+
+impl RandomSelectionBuffer {
+    /// Create a new random selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Random Selection Buffer"),
+            size: std::mem::size_of::<RandomSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the random selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, fraction: f32, seed: u32) {
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&RandomSelectionPod { fraction, seed }),
+        );
+    }
+}
+
+impl BufferWrapper for RandomSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The POD representation of a depth range selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct DepthRangeSelectionPod {
+    /// The view matrix, where the camera looks along -Z.
+    pub view: Mat4,
+    /// The near depth.
+    pub near: f32,
+    /// The far depth.
+    pub far: f32,
+    _padding: [f32; 2],
+}
+
+impl DepthRangeSelectionPod {
+    /// Create a new depth range selection.
+    pub fn new(view: Mat4, near: f32, far: f32) -> Self {
+        Self {
+            view,
+            near,
+            far,
+            _padding: [0.0; 2],
+        }
+    }
+}
+
+/// The depth range selection uniform buffer.
+#[derive(Debug, Clone)]
+pub struct DepthRangeSelectionBuffer(wgpu::Buffer);
+
+impl DepthRangeSelectionBuffer {
+    /// Create a new depth range selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Depth Range Selection Buffer"),
+            size: std::mem::size_of::<DepthRangeSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the depth range selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, view: Mat4, near: f32, far: f32) {
+        queue.write_buffer(
+            &self.0,
+            0,
+            bytemuck::bytes_of(&DepthRangeSelectionPod::new(view, near, far)),
+        );
+    }
+}
+
+impl BufferWrapper for DepthRangeSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The mesh selection uniform buffer.
+///
+/// This is the inverse of the transform of the mesh, the vertices are in the local space of the
+/// mesh, see [`MeshVerticesSelectionBuffer`] and [`MeshIndicesSelectionBuffer`].
+#[derive(Debug, Clone)]
+pub struct MeshSelectionBuffer(InvTransformBuffer);
+
+impl MeshSelectionBuffer {
+    /// Create a new mesh selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        Self(InvTransformBuffer::new(device))
+    }
+
+    /// Update the mesh selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, inv_transform: Mat4) {
+        self.0.update(queue, inv_transform);
+    }
+
+    /// Update the mesh selection buffer with the position, rotation, and scale.
+    pub fn update_with_pos_rot_scale(
+        &self,
+        queue: &wgpu::Queue,
+        pos: Vec3,
+        rot: Quat,
+        scale: Vec3,
+    ) {
+        let inv_transform = Mat4::from_scale_rotation_translation(scale, rot, pos).inverse();
+        self.update(queue, inv_transform);
+    }
+}
+
+impl BufferWrapper for MeshSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        self.0.buffer()
+    }
+}
+
+/// The mesh vertices selection storage buffer.
+///
+/// The buffer grows when more vertices are set than its capacity, in which case the bind group
+/// of the [`ops::mesh`](crate::ops::mesh) operation has to be recreated.
+#[derive(Debug, Clone)]
+pub struct MeshVerticesSelectionBuffer(HeaderArrayBuffer<u32, Vec3>);
+
+impl MeshVerticesSelectionBuffer {
+    /// Create a new mesh vertices selection buffer with space for `capacity` vertices.
+    pub fn new(device: &wgpu::Device, capacity: usize) -> Self {
+        Self(HeaderArrayBuffer::new(
+            device,
+            "Mesh Vertices Selection Buffer",
+            0,
+            capacity,
+        ))
+    }
+
+    /// Get the vertices.
+    pub fn vertices(&self) -> &[Vec3] {
+        &self.0.elements
+    }
+
+    /// Get the number of vertices the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Update the vertices of the mesh vertices selection buffer.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        vertices: &[Vec3],
+    ) -> bool {
+        self.0.elements.clear();
+        self.0.elements.extend_from_slice(vertices);
+        self.0.header = vertices.len() as u32;
+        self.0.upload(device, queue, 0)
+    }
+}
+
+impl BufferWrapper for MeshVerticesSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0.buffer
+    }
+}
+
+/// The mesh indices selection storage buffer.
+///
+/// Each triangle is the indices of its three vertices in [`MeshVerticesSelectionBuffer`], the
+/// triangles with indices out of range are ignored.
+///
+/// The buffer grows when more triangles are set than its capacity, in which case the bind group
+/// of the [`ops::mesh`](crate::ops::mesh) operation has to be recreated.
+#[derive(Debug, Clone)]
+pub struct MeshIndicesSelectionBuffer(HeaderArrayBuffer<u32, [u32; 3]>);
+
+impl MeshIndicesSelectionBuffer {
+    /// Create a new mesh indices selection buffer with space for `capacity` triangles.
+    pub fn new(device: &wgpu::Device, capacity: usize) -> Self {
+        Self(HeaderArrayBuffer::new(
+            device,
+            "Mesh Indices Selection Buffer",
+            0,
+            capacity,
+        ))
+    }
+
+    /// Get the triangles.
+    pub fn triangles(&self) -> &[[u32; 3]] {
+        &self.0.elements
+    }
+
+    /// Get the number of triangles the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Update the triangles of the mesh indices selection buffer.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        triangles: &[[u32; 3]],
+    ) -> bool {
+        self.0.elements.clear();
+        self.0.elements.extend_from_slice(triangles);
+        self.0.header = triangles.len() as u32;
+        self.0.upload(device, queue, 0)
+    }
+}
+
+impl BufferWrapper for MeshIndicesSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0.buffer
+    }
+}
+
+/// The POD representation of a signed distance field selection.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SdfSelectionPod {
+    /// The inverse transform of the SDF volume, which is a unit cube centered at the origin.
+    pub inv_transform: Mat4,
+    /// The distance below which the Gaussians are selected.
+    pub iso: f32,
+    _padding: [f32; 3],
+}
+
+impl SdfSelectionPod {
+    /// Create a new SDF selection.
+    pub fn new(inv_transform: Mat4, iso: f32) -> Self {
+        Self {
+            inv_transform,
+            iso,
+            _padding: [0.0; 3],
+        }
+    }
+}
+
+/// The signed distance field selection uniform buffer.
+///
+/// The distances are sampled from an [`SdfSelectionTexture`](crate::SdfSelectionTexture) which
+/// spans the unit cube centered at the origin after the inverse transform.
+#[derive(Debug, Clone)]
+pub struct SdfSelectionBuffer(wgpu::Buffer);
+
+impl SdfSelectionBuffer {
+    /// Create a new SDF selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("SDF Selection Buffer"),
+            size: std::mem::size_of::<SdfSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the SDF selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, sdf: &SdfSelectionPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(sdf));
+    }
+
+    /// Update the SDF selection buffer with the position, rotation, and scale of the volume.
+    pub fn update_with_pos_rot_scale_iso(
+        &self,
+        queue: &wgpu::Queue,
+        pos: Vec3,
+        rot: Quat,
+        scale: Vec3,
+        iso: f32,
+    ) {
+        let inv_transform = Mat4::from_scale_rotation_translation(scale, rot, pos).inverse();
+        self.update(queue, &SdfSelectionPod::new(inv_transform, iso));
+    }
+}
+
+impl BufferWrapper for SdfSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The POD representation of an image mask selection.
+///
+/// The pixel coordinates have the origin at the top left corner of the viewport.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MaskSelectionPod {
+    /// The view matrix.
+    pub view: Mat4,
+    /// The projection matrix.
+    pub proj: Mat4,
+    /// The size of the viewport in pixels.
+    pub viewport_size: Vec2,
+    /// The mask value above which a pixel is set.
+    pub threshold: f32,
+    _padding: f32,
+}
+
+impl MaskSelectionPod {
+    /// Create a new image mask selection.
+    pub fn new(view: Mat4, proj: Mat4, viewport_size: Vec2, threshold: f32) -> Self {
+        Self {
+            view,
+            proj,
+            viewport_size,
+            threshold,
+            _padding: 0.0,
+        }
+    }
+}
+
+/// The image mask selection uniform buffer.
+///
+/// The mask is a [`MaskSelectionTexture`](crate::MaskSelectionTexture) stretched over the
+/// viewport, so it does not have to have the same resolution as the viewport.
+#[derive(Debug, Clone)]
+pub struct MaskSelectionBuffer(wgpu::Buffer);
+
+impl MaskSelectionBuffer {
+    /// Create a new image mask selection buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Mask Selection Buffer"),
+            size: std::mem::size_of::<MaskSelectionPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the image mask selection buffer.
+    pub fn update(&self, queue: &wgpu::Queue, mask: &MaskSelectionPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(mask));
+    }
+}
+
+impl BufferWrapper for MaskSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The POD representation of the header of a multi-view image mask selection.
+///
+/// The view projection matrices of the views follow the header in
+/// [`MultiViewMaskSelectionBuffer`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MultiViewMaskSelectionPod {
+    /// The mask value above which a pixel is set.
+    pub threshold: f32,
+    /// The minimum number of views where the Gaussian is on a set pixel of the mask.
+    pub min_votes: u32,
+    view_count: u32,
+    _padding: u32,
+}
+
+impl MultiViewMaskSelectionPod {
+    /// Create a new multi-view image mask selection.
+    pub fn new(threshold: f32, min_votes: u32) -> Self {
+        Self {
+            threshold,
+            min_votes,
+            view_count: 0,
+            _padding: 0,
+        }
+    }
+
+    /// Get the number of views.
+    pub fn view_count(&self) -> u32 {
+        self.view_count
+    }
+}
+
+/// The multi-view image mask selection storage buffer.
+///
+/// Each view is a view projection matrix, and the view at index `i` corresponds to layer `i` of
+/// the [`MultiViewMaskSelectionTexture`](crate::MultiViewMaskSelectionTexture). Each mask is
+/// stretched over the viewport of its view.
+///
+/// The buffer grows when more views are set than its capacity, in which case the bind group of
+/// the [`ops::multi_view_mask`](crate::ops::multi_view_mask) operation has to be recreated.
+#[derive(Debug, Clone)]
+pub struct MultiViewMaskSelectionBuffer(HeaderArrayBuffer<MultiViewMaskSelectionPod, Mat4>);
+
+impl MultiViewMaskSelectionBuffer {
+    /// Create a new multi-view image mask selection buffer with space for `capacity` views.
+    ///
+    /// The header is uploaded along with the views.
+    pub fn new(device: &wgpu::Device, header: MultiViewMaskSelectionPod, capacity: usize) -> Self {
+        Self(HeaderArrayBuffer::new(
+            device,
+            "Multi-View Mask Selection Buffer",
+            MultiViewMaskSelectionPod {
+                view_count: 0,
+                ..header
+            },
+            capacity,
+        ))
+    }
+
+    /// Get the view projection matrices of the views.
+    pub fn views(&self) -> &[Mat4] {
+        &self.0.elements
+    }
+
+    /// Get the number of views the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity
+    }
+
+    /// Update the header of the multi-view image mask selection buffer.
+    ///
+    /// The view count of `header` is ignored.
+    pub fn update(&mut self, queue: &wgpu::Queue, header: MultiViewMaskSelectionPod) {
+        self.0.header = MultiViewMaskSelectionPod {
+            view_count: self.0.header.view_count,
+            ..header
+        };
+        self.0.write_header(queue);
+    }
+
+    /// Replace the views of the multi-view image mask selection buffer.
+    ///
+    /// Returns `true` if the buffer has grown, i.e. the bind group has to be recreated.
+    pub fn update_views(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view_projs: &[Mat4],
+    ) -> bool {
+        self.0.elements.clear();
+        self.0.elements.extend_from_slice(view_projs);
+        self.0.header.view_count = view_projs.len() as u32;
+        self.0.upload(device, queue, 0)
+    }
+}
+
+impl BufferWrapper for MultiViewMaskSelectionBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frustum_selection_pod_should_match_wgsl_layout() {
+        assert_eq!(std::mem::size_of::<FrustumSelectionPod>(), 80);
+        assert_eq!(std::mem::offset_of!(FrustumSelectionPod, view_proj), 0);
+        assert_eq!(std::mem::offset_of!(FrustumSelectionPod, near), 64);
+        assert_eq!(std::mem::offset_of!(FrustumSelectionPod, far), 68);
+        assert_eq!(
+            std::mem::offset_of!(FrustumSelectionPod, depth_override),
+            72
+        );
+    }
+
+    #[test]
+    fn test_inclusion_pod_should_match_wgsl_layout() {
+        assert_eq!(std::mem::size_of::<InclusionPod>(), 16);
+        assert_eq!(std::mem::offset_of!(InclusionPod, mode), 0);
+        assert_eq!(std::mem::offset_of!(InclusionPod, sigma), 4);
+    }
+
+    #[test]
+    fn test_check_plane_count_when_within_capacity_should_be_ok() {
+        assert!(check_plane_count(0, 4).is_ok());
+        assert!(check_plane_count(4, 4).is_ok());
+    }
+
+    #[test]
+    fn test_check_plane_count_when_exceeds_capacity_should_return_error() {
+        assert!(matches!(
+            check_plane_count(5, 4),
+            Err(Error::PlaneCountExceedsCapacity {
+                count: 5,
+                capacity: 4
+            })
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+use glam::*;
+
+use crate::core::BufferWrapper;
+
+/// The POD representation of a visibility test from a camera.
+///
+/// The pixel coordinates have the origin at the top left corner of the viewport.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct VisibilityPod {
+    /// The view matrix, where the camera looks along -Z.
+    pub view: Mat4,
+    /// The projection matrix.
+    pub proj: Mat4,
+    /// The size of the viewport in pixels.
+    pub viewport_size: Vec2,
+    /// The near depth of the depth bins.
+    pub near: f32,
+    /// The far depth of the depth bins.
+    pub far: f32,
+    /// The number of standard deviations of the projected footprint of the Gaussians, i.e. the k
+    /// in k-sigma.
+    pub sigma: f32,
+    /// The accumulated front to back alpha at or above which the Gaussians behind are occluded.
+    pub alpha_threshold: f32,
+    /// The maximum radius of the projected footprint of the Gaussians in cells of the
+    /// [`VisibilityDepthBuffer`].
+    pub max_radius: u32,
+    _padding: u32,
+}
+
+impl VisibilityPod {
+    /// Create a new visibility test.
+    ///
+    /// `near` and `far` are usually those of the camera. The sigma is 3, the alpha threshold is
+    /// 0.5, and the maximum radius is 32 cells.
+    pub fn new(view: Mat4, proj: Mat4, viewport_size: Vec2, near: f32, far: f32) -> Self {
+        Self {
+            view,
+            proj,
+            viewport_size,
+            near,
+            far,
+            sigma: 3.0,
+            alpha_threshold: 0.5,
+            max_radius: 32,
+            _padding: 0,
+        }
+    }
+}
+
+/// The visibility uniform buffer.
+///
+/// This is used by both [`VisibilityBundle`](crate::VisibilityBundle) and
+/// [`ops::visibility`](crate::ops::visibility).
+#[derive(Debug, Clone)]
+pub struct VisibilityBuffer(wgpu::Buffer);
+
+impl VisibilityBuffer {
+    /// Create a new visibility buffer.
+    pub fn new(device: &wgpu::Device) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Visibility Buffer"),
+            size: std::mem::size_of::<VisibilityPod>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self(buffer)
+    }
+
+    /// Update the visibility buffer.
+    pub fn update(&self, queue: &wgpu::Queue, visibility: &VisibilityPod) {
+        queue.write_buffer(&self.0, 0, bytemuck::bytes_of(visibility));
+    }
+}
+
+impl BufferWrapper for VisibilityBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.0
+    }
+}
+
+/// The depth storage buffer of the visibility pre-pass.
+///
+/// The viewport is divided into cells, each with depth bins spaced logarithmically between the
+/// near and far depths of the [`VisibilityPod`]. The cells do not have to match the pixels, a
+/// lower resolution than the viewport is usually enough and takes much less memory.
+///
+/// The buffer has a header of the size and bin count, followed by the accumulated optical depth
+/// of each bin of each cell, then the depth at which the accumulated alpha of each cell reaches
+/// the threshold.
+#[derive(Debug, Clone)]
+pub struct VisibilityDepthBuffer {
+    buffer: wgpu::Buffer,
+    size: UVec2,
+    bin_count: u32,
+}
+
+impl VisibilityDepthBuffer {
+    /// The size of the header.
+    pub const HEADER_SIZE: wgpu::BufferAddress =
+        std::mem::size_of::<UVec4>() as wgpu::BufferAddress;
+
+    /// Create a new visibility depth buffer with `size` cells and `bin_count` depth bins per cell.
+    pub fn new(device: &wgpu::Device, size: UVec2, bin_count: u32) -> Self {
+        let size = size.max(UVec2::ONE);
+        let bin_count = bin_count.max(1);
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Visibility Depth Buffer"),
+            size: Self::HEADER_SIZE
+                + (size.element_product() as usize
+                    * (bin_count as usize + 1)
+                    * std::mem::size_of::<u32>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: true,
+        });
+
+        buffer
+            .slice(..Self::HEADER_SIZE)
+            .get_mapped_range_mut()
+            .copy_from_slice(bytemuck::bytes_of(&UVec4::new(
+                size.x, size.y, bin_count, 0,
+            )));
+        buffer.unmap();
+
+        Self {
+            buffer,
+            size,
+            bin_count,
+        }
+    }
+
+    /// Get the number of cells.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Get the number of depth bins per cell.
+    pub fn bin_count(&self) -> u32 {
+        self.bin_count
+    }
+
+    /// Get the size of the depth bins in bytes.
+    pub fn bins_size(&self) -> wgpu::BufferAddress {
+        (self.size.element_product() as usize
+            * self.bin_count as usize
+            * std::mem::size_of::<u32>()) as wgpu::BufferAddress
+    }
+}
+
+impl BufferWrapper for VisibilityDepthBuffer {
+    fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visibility_pod_should_match_wgsl_layout() {
+        assert_eq!(std::mem::size_of::<VisibilityPod>(), 160);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, view), 0);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, proj), 64);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, viewport_size), 128);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, near), 136);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, far), 140);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, sigma), 144);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, alpha_threshold), 148);
+        assert_eq!(std::mem::offset_of!(VisibilityPod, max_radius), 152);
+    }
+}
@@ -6,4 +6,24 @@ use crate::core;
 pub enum Error {
     #[error("{0}")]
     Core(#[from] core::Error),
+    #[error("half angle {half_angle} is not between 0 and a right angle")]
+    InvalidHalfAngle { half_angle: f32 },
+    #[error("height {height} is not positive and finite")]
+    InvalidHeight { height: f32 },
+    #[error("plane count {count} exceeds the capacity {capacity}")]
+    PlaneCountExceedsCapacity { count: usize, capacity: usize },
+    #[error("texel count {count} does not match the texture size {expected}")]
+    TexelCountMismatch { count: usize, expected: usize },
+    #[error("layer count {count} does not match the texture layer count {expected}")]
+    LayerCountMismatch { count: usize, expected: usize },
+    #[error("at least one mask is required")]
+    NoMasks,
+    #[error("cell size {cell_size} is not positive and finite")]
+    InvalidCellSize { cell_size: f32 },
+    #[error("nearest neighbor count {k} is not between 1 and {max}")]
+    InvalidNeighborCount { k: u32, max: u32 },
+    #[error("standard deviation multiplier {std_dev_multiplier} is not finite")]
+    InvalidStdDevMultiplier { std_dev_multiplier: f32 },
+    #[error("maximum search radius {max_search_radius} is not positive")]
+    InvalidMaxSearchRadius { max_search_radius: u32 },
 }
@@ -1,12 +1,29 @@
 #![doc = include_str!("../README.md")]
 
+macro_rules! package_module_path {
+    ($($components:ident)::+) => {
+        wesl::ModulePath {
+            origin: wesl::syntax::PathOrigin::Package,
+            components: vec![$(stringify!($components).to_string()),+],
+        }
+    }
+}
+
 mod buffer;
 mod error;
+mod outlier;
+mod pick;
 mod selection;
 pub mod shader;
+mod texture;
+mod visibility;
 
 pub use buffer::*;
 pub use error::*;
+pub use outlier::*;
+pub use pick::*;
 pub use selection::*;
+pub use texture::*;
+pub use visibility::*;
 
 pub use wgpu_3dgs_core as core;
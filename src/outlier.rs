@@ -0,0 +1,268 @@
+use crate::{
+    OutlierBucketsBuffer, OutlierBuffer, OutlierDistancesBuffer,
+    core::{
+        self, BufferWrapper, ComputeBundle, ComputeBundleBuilder, GaussianPod,
+        GaussianTransformBuffer, GaussiansBuffer, ModelTransformBuffer,
+    },
+    shader,
+};
+
+/// A bundle for computing the nearest neighbor distances of the statistical outlier filter.
+///
+/// The k nearest neighbors are searched in a spatial hash grid, which is built in two passes:
+///
+/// 1. The Gaussians are inserted into the lists of their hash buckets with `atomicExchange`.
+/// 2. The mean distance to the k nearest neighbors is computed for each Gaussian by searching
+///    growing shells of cells up to the maximum search radius, and accumulated into the global
+///    mean and standard deviation, see [`OutlierPod`](crate::OutlierPod).
+///
+/// The result is written to an [`OutlierDistancesBuffer`], which is used by
+/// [`ops::outlier`](crate::ops::outlier) to select the Gaussians with mean distance more than
+/// the number of standard deviations above the global mean, i.e. the floaters.
+///
+/// ```wgsl
+/// import wgpu_3dgs_core::{
+///     gaussian::Gaussian,
+///     gaussian_transform::GaussianTransform,
+///     model_transform::ModelTransform,
+/// };
+///
+/// @group(0) @binding(0)
+/// var<uniform> outlier: Outlier;
+///
+/// @group(0) @binding(1)
+/// var<storage, read_write> buckets: array<atomic<u32>>;
+///
+/// @group(0) @binding(2)
+/// var<storage, read_write> distances: OutlierDistances;
+///
+/// @group(0) @binding(3)
+/// var<uniform> model_transform: ModelTransform;
+///
+/// @group(0) @binding(4)
+/// var<uniform> gaussian_transform: GaussianTransform;
+///
+/// @group(0) @binding(5)
+/// var<storage, read> gaussians: array<Gaussian>;
+/// ```
+#[derive(Debug)]
+pub struct OutlierBundle {
+    /// The compute bundle for inserting the Gaussians into the hash buckets.
+    pub insert_bundle: ComputeBundle<()>,
+    /// The compute bundle for computing the nearest neighbor distances.
+    pub distance_bundle: ComputeBundle<()>,
+    /// The hash buckets, which are only used while computing.
+    buckets: OutlierBucketsBuffer,
+}
+
+impl OutlierBundle {
+    /// The distance bind group layout descriptor of both passes.
+    ///
+    /// This is different from
+    /// [`ops::OUTLIER_BIND_GROUP_LAYOUT_DESCRIPTOR`](crate::ops::OUTLIER_BIND_GROUP_LAYOUT_DESCRIPTOR),
+    /// which is for selecting the outliers.
+    pub const DISTANCE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Outlier Distance Bind Group Layout"),
+            entries: &[
+                // Outlier uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Buckets storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Distances storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Model transform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Gaussian transform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Gaussian buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 5,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// Create a new outlier bundle with `bucket_count` hash buckets.
+    ///
+    /// More buckets reduce the collisions of different cells at the cost of memory, around the
+    /// number of Gaussians is a good start.
+    pub fn new<G: GaussianPod>(device: &wgpu::Device, bucket_count: u32) -> Self {
+        let insert_bundle = Self::create_bundle::<G>(device, "Outlier Insert", "insert_main");
+        let distance_bundle = Self::create_bundle::<G>(device, "Outlier Distance", "distance_main");
+        let buckets = OutlierBucketsBuffer::new(device, bucket_count);
+
+        Self {
+            insert_bundle,
+            distance_bundle,
+            buckets,
+        }
+    }
+
+    /// Get the distance bind group layout.
+    pub fn distance_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.distance_bundle.bind_group_layouts()[0]
+    }
+
+    /// Get the hash buckets buffer.
+    pub fn buckets(&self) -> &OutlierBucketsBuffer {
+        &self.buckets
+    }
+
+    /// Create the distance bind group.
+    ///
+    /// Both passes have the same layout, so the bind group is shared by them.
+    pub fn create_bind_group<G: GaussianPod>(
+        &self,
+        device: &wgpu::Device,
+        outlier: &OutlierBuffer,
+        distances: &OutlierDistancesBuffer,
+        model_transform: &ModelTransformBuffer,
+        gaussian_transform: &GaussianTransformBuffer,
+        gaussians: &GaussiansBuffer<G>,
+    ) -> OutlierDistanceBindGroup {
+        let bind_group = self
+            .distance_bundle
+            .create_bind_group(
+                device,
+                0,
+                [
+                    outlier as &dyn BufferWrapper,
+                    &self.buckets as &dyn BufferWrapper,
+                    distances as &dyn BufferWrapper,
+                    model_transform as &dyn BufferWrapper,
+                    gaussian_transform as &dyn BufferWrapper,
+                    gaussians as &dyn BufferWrapper,
+                ],
+            )
+            .expect("distance bind group");
+
+        OutlierDistanceBindGroup {
+            bind_group,
+            distances: distances.clone(),
+        }
+    }
+
+    /// Compute the nearest neighbor distances and their global statistics.
+    ///
+    /// The buckets and the statistics are cleared before computing, so this has to be recorded
+    /// before the [`ops::outlier`](crate::ops::outlier) operation is evaluated whenever the
+    /// parameters or the Gaussians change.
+    pub fn compute(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        gaussian_count: u32,
+        bind_group: &OutlierDistanceBindGroup,
+    ) {
+        encoder.clear_buffer(self.buckets.buffer(), 0, None);
+        encoder.clear_buffer(
+            bind_group.distances().buffer(),
+            0,
+            Some(OutlierDistancesBuffer::STATS_SIZE),
+        );
+
+        for bundle in [&self.insert_bundle, &self.distance_bundle] {
+            bundle.dispatch(encoder, gaussian_count, [bind_group.bind_group()]);
+        }
+    }
+
+    /// Create an outlier pass [`ComputeBundle`].
+    fn create_bundle<G: GaussianPod>(
+        device: &wgpu::Device,
+        label: &str,
+        entry_point: &str,
+    ) -> ComputeBundle<()> {
+        let mut resolver = wesl::PkgResolver::new();
+        resolver.add_package(&core::shader::Mod);
+        resolver.add_package(&shader::Mod);
+
+        ComputeBundleBuilder::new()
+            .label(label)
+            .bind_group(&Self::DISTANCE_BIND_GROUP_LAYOUT_DESCRIPTOR)
+            .main_shader(package_module_path!(
+                wgpu_3dgs_editor::selection::outlier_distance
+            ))
+            .entry_point(entry_point)
+            .compile_options(wesl::CompileOptions {
+                features: G::features_map(),
+                ..Default::default()
+            })
+            .resolver(resolver)
+            .build_without_bind_groups(device)
+            .map_err(|e| log::error!("{e}"))
+            .expect("outlier bundle")
+    }
+}
+
+/// The distance bind group of [`OutlierBundle`].
+///
+/// It holds on to the [`OutlierDistancesBuffer`] that it binds, which is where
+/// [`OutlierBundle::compute`] clears the statistics before the passes accumulate them.
+#[derive(Debug)]
+pub struct OutlierDistanceBindGroup {
+    bind_group: wgpu::BindGroup,
+    distances: OutlierDistancesBuffer,
+}
+
+impl OutlierDistanceBindGroup {
+    /// Get the bind group.
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// Get the distances buffer.
+    pub fn distances(&self) -> &OutlierDistancesBuffer {
+        &self.distances
+    }
+}
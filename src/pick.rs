@@ -0,0 +1,246 @@
+use crate::{
+    RayPickBuffer, RayPickResultBuffer,
+    core::{
+        self, BufferWrapper, ComputeBundle, ComputeBundleBuilder, GaussianPod,
+        GaussianTransformBuffer, GaussiansBuffer, ModelTransformBuffer,
+    },
+    shader,
+};
+
+/// A bundle for picking the nearest Gaussian hit by a ray.
+///
+/// Unlike the operations of [`SelectionBundle`](crate::SelectionBundle), picking is a reduction
+/// over all Gaussians, so it is done in two passes:
+///
+/// 1. The minimum hit distance to the k-sigma ellipsoids is found with `atomicMin`.
+/// 2. The minimum index of the Gaussians hit at that distance is found with `atomicMin`.
+///
+/// The result is written to a [`RayPickResultBuffer`], which can be downloaded or used by
+/// [`ops::ray_pick`](crate::ops::ray_pick) to set the picked Gaussian in a
+/// [`SelectionBuffer`](crate::SelectionBuffer).
+///
+/// ```wgsl
+/// import wgpu_3dgs_core::{
+///     gaussian::Gaussian,
+///     gaussian_transform::GaussianTransform,
+///     model_transform::ModelTransform,
+/// };
+///
+/// @group(0) @binding(0)
+/// var<uniform> ray: Ray;
+///
+/// @group(0) @binding(1)
+/// var<storage, read_write> result: RayPickResult;
+///
+/// @group(0) @binding(2)
+/// var<uniform> model_transform: ModelTransform;
+///
+/// @group(0) @binding(3)
+/// var<uniform> gaussian_transform: GaussianTransform;
+///
+/// @group(0) @binding(4)
+/// var<storage, read> gaussians: array<Gaussian>;
+/// ```
+#[derive(Debug)]
+pub struct RayPickBundle {
+    /// The compute bundle for finding the minimum hit distance.
+    pub distance_bundle: ComputeBundle<()>,
+    /// The compute bundle for finding the index at the minimum hit distance.
+    pub index_bundle: ComputeBundle<()>,
+    /// The buffer to reset the result with before picking.
+    reset: RayPickResultBuffer,
+}
+
+impl RayPickBundle {
+    /// The ray bind group layout descriptor of both passes.
+    ///
+    /// This is different from
+    /// [`ops::RAY_PICK_BIND_GROUP_LAYOUT_DESCRIPTOR`](crate::ops::RAY_PICK_BIND_GROUP_LAYOUT_DESCRIPTOR),
+    /// which is for selecting the picked Gaussian.
+    pub const RAY_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Ray Pick Bind Group Layout"),
+            entries: &[
+                // Ray uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Result storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Model transform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Gaussian transform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Gaussian buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// Create a new ray pick bundle.
+    pub fn new<G: GaussianPod>(device: &wgpu::Device) -> Self {
+        let distance_bundle =
+            Self::create_bundle::<G>(device, "Ray Pick Distance", "distance_main");
+        let index_bundle = Self::create_bundle::<G>(device, "Ray Pick Index", "index_main");
+        let reset = RayPickResultBuffer::new(device);
+
+        Self {
+            distance_bundle,
+            index_bundle,
+            reset,
+        }
+    }
+
+    /// Get the ray bind group layout.
+    pub fn ray_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.distance_bundle.bind_group_layouts()[0]
+    }
+
+    /// Create the ray bind group.
+    ///
+    /// Both passes have the same layout, so the bind group is shared by them.
+    pub fn create_bind_group<G: GaussianPod>(
+        &self,
+        device: &wgpu::Device,
+        ray: &RayPickBuffer,
+        result: &RayPickResultBuffer,
+        model_transform: &ModelTransformBuffer,
+        gaussian_transform: &GaussianTransformBuffer,
+        gaussians: &GaussiansBuffer<G>,
+    ) -> RayPickBindGroup {
+        let bind_group = self
+            .distance_bundle
+            .create_bind_group(
+                device,
+                0,
+                [
+                    ray as &dyn BufferWrapper,
+                    result as &dyn BufferWrapper,
+                    model_transform as &dyn BufferWrapper,
+                    gaussian_transform as &dyn BufferWrapper,
+                    gaussians as &dyn BufferWrapper,
+                ],
+            )
+            .expect("ray bind group");
+
+        RayPickBindGroup {
+            bind_group,
+            result: result.clone(),
+        }
+    }
+
+    /// Pick the nearest Gaussian hit by the ray.
+    ///
+    /// The result is reset before picking, so [`RayPickResultBuffer::NONE`] is written when no
+    /// Gaussian is hit.
+    pub fn pick(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        gaussian_count: u32,
+        bind_group: &RayPickBindGroup,
+    ) {
+        let result = bind_group.result();
+        encoder.copy_buffer_to_buffer(
+            self.reset.buffer(),
+            0,
+            result.buffer(),
+            0,
+            result.buffer().size(),
+        );
+
+        for bundle in [&self.distance_bundle, &self.index_bundle] {
+            bundle.dispatch(encoder, gaussian_count, [bind_group.bind_group()]);
+        }
+    }
+
+    /// Create a ray pick pass [`ComputeBundle`].
+    fn create_bundle<G: GaussianPod>(
+        device: &wgpu::Device,
+        label: &str,
+        entry_point: &str,
+    ) -> ComputeBundle<()> {
+        let mut resolver = wesl::PkgResolver::new();
+        resolver.add_package(&core::shader::Mod);
+        resolver.add_package(&shader::Mod);
+
+        ComputeBundleBuilder::new()
+            .label(label)
+            .bind_group(&Self::RAY_BIND_GROUP_LAYOUT_DESCRIPTOR)
+            .main_shader(package_module_path!(
+                wgpu_3dgs_editor::selection::ray_pick_reduce
+            ))
+            .entry_point(entry_point)
+            .compile_options(wesl::CompileOptions {
+                features: G::features_map(),
+                ..Default::default()
+            })
+            .resolver(resolver)
+            .build_without_bind_groups(device)
+            .map_err(|e| log::error!("{e}"))
+            .expect("ray pick bundle")
+    }
+}
+
+/// The ray bind group of [`RayPickBundle`].
+///
+/// The bind group keeps the [`RayPickResultBuffer`] it is created with, so the result reset by
+/// [`RayPickBundle::pick`] is always the one written by the passes.
+#[derive(Debug)]
+pub struct RayPickBindGroup {
+    bind_group: wgpu::BindGroup,
+    result: RayPickResultBuffer,
+}
+
+impl RayPickBindGroup {
+    /// Get the bind group.
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// Get the result buffer.
+    pub fn result(&self) -> &RayPickResultBuffer {
+        &self.result
+    }
+}
@@ -9,15 +9,6 @@ use crate::{
     shader,
 };
 
-macro_rules! package_module_path {
-    ($($components:ident)::+) => {
-        wesl::ModulePath {
-            origin: wesl::syntax::PathOrigin::Package,
-            components: vec![$(stringify!($components).to_string()),+],
-        }
-    }
-}
-
 /// A selection expression tree.
 ///
 /// This can be used to carry out operations on selection buffers.
@@ -457,6 +448,18 @@ impl SelectionBundle {
     }
 }
 
+/// The selection operations.
+///
+/// Each operation is a [`ComputeBundle`] to be used in [`SelectionExpr::Selection`], with
+/// [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`] as bind group 0 and an operation
+/// specific bind group 1, which is created with [`ComputeBundle::create_bind_group`] from the
+/// buffers of the operation.
+///
+/// The exceptions are [`sdf`](crate::ops::sdf), [`mask`](crate::ops::mask), and
+/// [`multi_view_mask`](crate::ops::multi_view_mask), which bind a texture.
+/// [`ComputeBundle::create_bind_group`] only accepts buffers, so their bind group 1 is created by
+/// the `create_bind_group` of their textures with the bind group layout of the bundle instead,
+/// e.g. [`SdfSelectionTexture::create_bind_group`](crate::SdfSelectionTexture::create_bind_group).
 pub mod ops {
     use super::*;
 
@@ -476,25 +479,1167 @@ pub mod ops {
                     },
                     count: None,
                 },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         };
 
-    /// Create a sphere selection operation.
-    ///
-    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
-    /// - Bind group 1 is [`SPHERE_BIND_GROUP_LAYOUT_DESCRIPTOR`].
-    pub fn sphere<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+    /// The box selection bind group layout descriptor.
+    pub const BOX_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Box Selection Bind Group Layout"),
+            entries: &[
+                // Box uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The cone selection bind group layout descriptor.
+    pub const CONE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Cone Selection Bind Group Layout"),
+            entries: &[
+                // Cone uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The cylinder selection bind group layout descriptor.
+    pub const CYLINDER_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Cylinder Selection Bind Group Layout"),
+            entries: &[
+                // Cylinder uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The capsule selection bind group layout descriptor.
+    pub const CAPSULE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Capsule Selection Bind Group Layout"),
+            entries: &[
+                // Capsule uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The half space selection bind group layout descriptor.
+    pub const HALF_SPACE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Half Space Selection Bind Group Layout"),
+            entries: &[
+                // Plane uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The convex polytope selection bind group layout descriptor.
+    pub const CONVEX_POLYTOPE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<
+        'static,
+    > = wgpu::BindGroupLayoutDescriptor {
+        label: Some("Convex Polytope Selection Bind Group Layout"),
+        entries: &[
+            // Convex polytope storage buffer
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            // Inclusion uniform buffer
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+        ],
+    };
+
+    /// The frustum selection bind group layout descriptor.
+    pub const FRUSTUM_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Frustum Selection Bind Group Layout"),
+            entries: &[
+                // Frustum uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The rectangle selection bind group layout descriptor.
+    pub const RECT_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Rect Selection Bind Group Layout"),
+            entries: &[
+                // Rect uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The lasso selection bind group layout descriptor.
+    pub const LASSO_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Lasso Selection Bind Group Layout"),
+            entries: &[
+                // Lasso storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The brush selection bind group layout descriptor.
+    pub const BRUSH_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Brush Selection Bind Group Layout"),
+            entries: &[
+                // Brush storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The opacity range selection bind group layout descriptor.
+    pub const OPACITY_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Opacity Range Selection Bind Group Layout"),
+            entries: &[
+                // Opacity range uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The scale range selection bind group layout descriptor.
+    pub const SCALE_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Scale Range Selection Bind Group Layout"),
+            entries: &[
+                // Scale range uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The anisotropy selection bind group layout descriptor.
+    pub const ANISOTROPY_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Anisotropy Selection Bind Group Layout"),
+            entries: &[
+                // Anisotropy threshold uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The color range selection bind group layout descriptor.
+    pub const COLOR_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Color Range Selection Bind Group Layout"),
+            entries: &[
+                // Color range uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The view dependent color range selection bind group layout descriptor.
+    pub const VIEW_COLOR_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<
+        'static,
+    > = wgpu::BindGroupLayoutDescriptor {
+        label: Some("View Color Range Selection Bind Group Layout"),
+        entries: &[
+            // View color range uniform buffer
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+        ],
+    };
+
+    /// The indices selection bind group layout descriptor.
+    pub const INDICES_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Indices Selection Bind Group Layout"),
+            entries: &[
+                // Indices storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The index range selection bind group layout descriptor.
+    pub const INDEX_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Index Range Selection Bind Group Layout"),
+            entries: &[
+                // Index range uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The random selection bind group layout descriptor.
+    pub const RANDOM_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Random Selection Bind Group Layout"),
+            entries: &[
+                // Random uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The depth range selection bind group layout descriptor.
+    pub const DEPTH_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Depth Range Selection Bind Group Layout"),
+            entries: &[
+                // Depth range uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Inclusion uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The ray pick selection bind group layout descriptor.
+    pub const RAY_PICK_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Ray Pick Selection Bind Group Layout"),
+            entries: &[
+                // Ray pick result storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The mesh selection bind group layout descriptor.
+    pub const MESH_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Mesh Selection Bind Group Layout"),
+            entries: &[
+                // Mesh uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Mesh vertices storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Mesh indices storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The SDF selection bind group layout descriptor.
+    pub const SDF_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("SDF Selection Bind Group Layout"),
+            entries: &[
+                // SDF uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // SDF texture
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D3,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The image mask selection bind group layout descriptor.
+    pub const MASK_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Mask Selection Bind Group Layout"),
+            entries: &[
+                // Mask uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Mask texture
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The multi-view image mask selection bind group layout descriptor.
+    pub const MULTI_VIEW_MASK_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<
+        'static,
+    > = wgpu::BindGroupLayoutDescriptor {
+        label: Some("Multi-View Mask Selection Bind Group Layout"),
+        entries: &[
+            // Multi-view mask storage buffer
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+            // Mask texture array
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2Array,
+                    multisampled: false,
+                },
+                count: None,
+            },
+        ],
+    };
+
+    /// The visibility selection bind group layout descriptor.
+    pub const VISIBILITY_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Visibility Selection Bind Group Layout"),
+            entries: &[
+                // Visibility uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Visibility depth storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// The statistical outlier selection bind group layout descriptor.
+    pub const OUTLIER_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Outlier Selection Bind Group Layout"),
+            entries: &[
+                // Outlier uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Outlier distances storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// Create a sphere selection operation.
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`SPHERE_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn sphere<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Sphere Selection",
+            &SPHERE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::sphere),
+        )
+    }
+
+    /// Create a box selection operation.
+    ///
+    /// The box is the unit cube centered at the origin, i.e. spanning from -0.5 to 0.5 on each
+    /// axis, transformed by the inverse of [`BoxSelectionBuffer`](crate::BoxSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`BOX_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn box_<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Box Selection",
+            &BOX_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::box),
+        )
+    }
+
+    /// Create a cone selection operation.
+    ///
+    /// The cone has its apex at the origin and opens along the +Z axis, with a height and base
+    /// radius of 1, transformed by the inverse of
+    /// [`ConeSelectionBuffer`](crate::ConeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`CONE_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn cone<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Cone Selection",
+            &CONE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::cone),
+        )
+    }
+
+    /// Create a cylinder selection operation.
+    ///
+    /// The cylinder is centered at the origin with its axis along Z, a radius of 1 and spanning
+    /// from -1 to 1 on the axis, transformed by the inverse of
+    /// [`CylinderSelectionBuffer`](crate::CylinderSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`CYLINDER_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn cylinder<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Cylinder Selection",
+            &CYLINDER_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::cylinder),
+        )
+    }
+
+    /// Create a capsule selection operation.
+    ///
+    /// The capsule is centered at the origin with its axis along Z, see
+    /// [`CapsuleSelectionBuffer`](crate::CapsuleSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`CAPSULE_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn capsule<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Capsule Selection",
+            &CAPSULE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::capsule),
+        )
+    }
+
+    /// Create a half space selection operation.
+    ///
+    /// The Gaussians on the side of the plane which the normal points to, i.e. with
+    /// `dot(normal, pos) >= offset` in world space, are selected, see
+    /// [`PlaneSelectionBuffer`](crate::PlaneSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`HALF_SPACE_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn half_space<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Half Space Selection",
+            &HALF_SPACE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::half_space),
+        )
+    }
+
+    /// Create a convex polytope selection operation.
+    ///
+    /// The Gaussians inside all the half spaces of the planes are selected, each plane follows the
+    /// same convention as [`half_space`], see
+    /// [`ConvexPolytopeSelectionBuffer`](crate::ConvexPolytopeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`CONVEX_POLYTOPE_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn convex_polytope<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Convex Polytope Selection",
+            &CONVEX_POLYTOPE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::convex_polytope),
+        )
+    }
+
+    /// Create a frustum selection operation.
+    ///
+    /// The Gaussians with world position inside the clip volume of the view projection matrix are
+    /// selected, see [`FrustumSelectionBuffer`](crate::FrustumSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`FRUSTUM_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn frustum<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Frustum Selection",
+            &FRUSTUM_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::frustum),
+        )
+    }
+
+    /// Create a screen space rectangle selection operation.
+    ///
+    /// The Gaussians with center projected inside the pixel rectangle of the viewport are selected,
+    /// see [`RectSelectionBuffer`](crate::RectSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`RECT_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn rect<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Rect Selection",
+            &RECT_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::rect),
+        )
+    }
+
+    /// Create a screen space lasso selection operation.
+    ///
+    /// The Gaussians with center projected inside the pixel polygon of the viewport are selected,
+    /// see [`LassoSelectionBuffer`](crate::LassoSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`LASSO_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn lasso<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Lasso Selection",
+            &LASSO_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::lasso),
+        )
+    }
+
+    /// Create a screen space brush selection operation.
+    ///
+    /// The Gaussians with center projected inside any disc of the stroke samples are selected, see
+    /// [`BrushSelectionBuffer`](crate::BrushSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`BRUSH_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn brush<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Brush Selection",
+            &BRUSH_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::brush),
+        )
+    }
+
+    /// Create an opacity range selection operation.
+    ///
+    /// The Gaussians with decoded opacity within the inclusive range are selected, see
+    /// [`OpacityRangeSelectionBuffer`](crate::OpacityRangeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`OPACITY_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn opacity_range<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Opacity Range Selection",
+            &OPACITY_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::opacity_range),
+        )
+    }
+
+    /// Create a scale range selection operation.
+    ///
+    /// The Gaussians with the chosen axis scale within the inclusive range are selected, see
+    /// [`ScaleRangeSelectionBuffer`](crate::ScaleRangeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`SCALE_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn scale_range<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Scale Range Selection",
+            &SCALE_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::scale_range),
+        )
+    }
+
+    /// Create an anisotropy selection operation.
+    ///
+    /// The Gaussians with the ratio of the largest to the smallest covariance eigenvalue at or above
+    /// the threshold are selected, see
+    /// [`AnisotropySelectionBuffer`](crate::AnisotropySelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`ANISOTROPY_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn anisotropy<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Anisotropy Selection",
+            &ANISOTROPY_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::anisotropy),
+        )
+    }
+
+    /// Create a color range selection operation.
+    ///
+    /// The Gaussians with base color, i.e. the color decoded from the DC term of the spherical
+    /// harmonics, within the distance of the reference color are selected, see
+    /// [`ColorRangeSelectionBuffer`](crate::ColorRangeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`COLOR_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn color_range<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Color Range Selection",
+            &COLOR_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::color_range),
+        )
+    }
+
+    /// Create a view dependent color range selection operation.
+    ///
+    /// The Gaussians with color evaluated from the spherical harmonics for the view direction within
+    /// the distance of the reference color are selected, see
+    /// [`ViewColorRangeSelectionBuffer`](crate::ViewColorRangeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`VIEW_COLOR_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn view_color_range<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "View Color Range Selection",
+            &VIEW_COLOR_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::view_color_range),
+        )
+    }
+
+    /// Create an indices selection operation.
+    ///
+    /// The Gaussians with index in the list are selected, see
+    /// [`IndicesSelectionBuffer`](crate::IndicesSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`INDICES_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn indices<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Indices Selection",
+            &INDICES_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::indices),
+        )
+    }
+
+    /// Create an index range selection operation.
+    ///
+    /// The Gaussians with index in the half open range are selected, see
+    /// [`IndexRangeSelectionBuffer`](crate::IndexRangeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`INDEX_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn index_range<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Index Range Selection",
+            &INDEX_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::index_range),
+        )
+    }
+
+    /// Create a random selection operation.
+    ///
+    /// A fraction of the Gaussians is selected by a deterministic hash of the index and the seed,
+    /// see [`RandomSelectionBuffer`](crate::RandomSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`RANDOM_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn random<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Random Selection",
+            &RANDOM_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::random),
+        )
+    }
+
+    /// Create a depth range selection operation.
+    ///
+    /// The Gaussians with view space depth from the camera within the range are selected, see
+    /// [`DepthRangeSelectionBuffer`](crate::DepthRangeSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`DEPTH_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR`], with an
+    ///   [`InclusionBuffer`](crate::InclusionBuffer) at binding 1.
+    pub fn depth_range<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Depth Range Selection",
+            &DEPTH_RANGE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::depth_range),
+        )
+    }
+
+    /// Create a ray pick selection operation.
+    ///
+    /// The Gaussian picked by [`RayPickBundle`](crate::RayPickBundle) is selected, see
+    /// [`RayPickResultBuffer`](crate::RayPickResultBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`RAY_PICK_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn ray_pick<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Ray Pick Selection",
+            &RAY_PICK_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::ray_pick),
+        )
+    }
+
+    /// Create a triangle mesh selection operation.
+    ///
+    /// The Gaussians with centers inside the closed mesh are selected using the generalized
+    /// winding number, which also tolerates small holes in the mesh, see
+    /// [`MeshSelectionBuffer`](crate::MeshSelectionBuffer),
+    /// [`MeshVerticesSelectionBuffer`](crate::MeshVerticesSelectionBuffer), and
+    /// [`MeshIndicesSelectionBuffer`](crate::MeshIndicesSelectionBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`MESH_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn mesh<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Mesh Selection",
+            &MESH_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::mesh),
+        )
+    }
+
+    /// Create a signed distance field selection operation.
+    ///
+    /// The Gaussians with centers inside the volume where the trilinearly interpolated distance is
+    /// below the iso value are selected, see [`SdfSelectionBuffer`](crate::SdfSelectionBuffer).
+    ///
+    /// The bind group 1 can be created with
+    /// [`SdfSelectionTexture::create_bind_group`](crate::SdfSelectionTexture::create_bind_group).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`SDF_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn sdf<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "SDF Selection",
+            &SDF_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::sdf),
+        )
+    }
+
+    /// Create an image mask selection operation.
+    ///
+    /// The Gaussians with projected centers on the set pixels of the mask are selected, see
+    /// [`MaskSelectionBuffer`](crate::MaskSelectionBuffer).
+    ///
+    /// The bind group 1 can be created with
+    /// [`MaskSelectionTexture::create_bind_group`](crate::MaskSelectionTexture::create_bind_group).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`MASK_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn mask<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Mask Selection",
+            &MASK_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::mask),
+        )
+    }
+
+    /// Create a multi-view image mask selection operation.
+    ///
+    /// The Gaussians with projected centers on the set pixels of the masks in at least the minimum
+    /// number of views are selected, where only the views with the Gaussian in the frustum vote,
+    /// see [`MultiViewMaskSelectionBuffer`](crate::MultiViewMaskSelectionBuffer).
+    ///
+    /// The bind group 1 can be created with
+    /// [`MultiViewMaskSelectionTexture::create_bind_group`](crate::MultiViewMaskSelectionTexture::create_bind_group).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`MULTI_VIEW_MASK_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn multi_view_mask<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Multi-View Mask Selection",
+            &MULTI_VIEW_MASK_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::multi_view_mask),
+        )
+    }
+
+    /// Create a visibility selection operation.
+    ///
+    /// The Gaussians with projected centers in the viewport and not behind the depth at which the
+    /// accumulated alpha reaches the threshold, rendered by
+    /// [`VisibilityBundle::render_depth`](crate::VisibilityBundle::render_depth), are selected,
+    /// see [`VisibilityBuffer`](crate::VisibilityBuffer) and
+    /// [`VisibilityDepthBuffer`](crate::VisibilityDepthBuffer).
+    ///
+    /// This can be intersected with [`rect`] or [`lasso`] to select only the visible Gaussians.
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`VISIBILITY_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn visibility<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Visibility Selection",
+            &VISIBILITY_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::visibility),
+        )
+    }
+
+    /// Create a statistical outlier selection operation.
+    ///
+    /// The Gaussians with mean distance to their k nearest neighbors more than the number of standard
+    /// deviations above the global mean computed by
+    /// [`OutlierBundle::compute`](crate::OutlierBundle::compute) are selected, see
+    /// [`OutlierBuffer`](crate::OutlierBuffer) and
+    /// [`OutlierDistancesBuffer`](crate::OutlierDistancesBuffer).
+    ///
+    /// - Bind group 0 is [`SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    /// - Bind group 1 is [`OUTLIER_BIND_GROUP_LAYOUT_DESCRIPTOR`].
+    pub fn outlier<G: GaussianPod>(device: &wgpu::Device) -> ComputeBundle<()> {
+        create::<G>(
+            device,
+            "Outlier Selection",
+            &OUTLIER_BIND_GROUP_LAYOUT_DESCRIPTOR,
+            package_module_path!(wgpu_3dgs_editor::selection::outlier),
+        )
+    }
+
+    /// Create a selection operation [`ComputeBundle`] with the Gaussians bind group and an
+    /// operation specific bind group.
+    fn create<G: GaussianPod>(
+        device: &wgpu::Device,
+        label: &str,
+        bind_group_layout_descriptor: &wgpu::BindGroupLayoutDescriptor<'static>,
+        main_shader: wesl::ModulePath,
+    ) -> ComputeBundle<()> {
         let mut resolver = wesl::PkgResolver::new();
         resolver.add_package(&core::shader::Mod);
         resolver.add_package(&shader::Mod);
 
         ComputeBundleBuilder::new()
-            .label("Sphere Selection")
+            .label(label)
             .bind_groups([
                 &SelectionBundle::GAUSSIANS_BIND_GROUP_LAYOUT_DESCRIPTOR,
-                &SPHERE_BIND_GROUP_LAYOUT_DESCRIPTOR,
+                bind_group_layout_descriptor,
             ])
-            .main_shader(package_module_path!(wgpu_3dgs_editor::selection::sphere))
+            .main_shader(main_shader)
             .entry_point("main")
             .compile_options(wesl::CompileOptions {
                 features: G::features_map(),
@@ -503,6 +1648,6 @@ pub mod ops {
             .resolver(resolver)
             .build_without_bind_groups(device)
             .map_err(|e| log::error!("{e}"))
-            .expect("sphere selection compute bundle")
+            .expect("selection operation compute bundle")
     }
 }
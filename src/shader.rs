@@ -23,25 +23,57 @@ impl PkgModule for Mod {
             "ops" => Some(&selection::ops::Mod),
             "primitive_ops" => Some(&selection::primitive_ops::Mod),
             "utils" => Some(&selection::utils::Mod),
+            "inclusion" => Some(&selection::inclusion::Mod),
+            "color" => Some(&selection::color::Mod),
+            "sh" => Some(&selection::sh::Mod),
             "sphere" => Some(&selection::sphere::Mod),
+            "box" => Some(&selection::box_::Mod),
+            "cone" => Some(&selection::cone::Mod),
+            "cylinder" => Some(&selection::cylinder::Mod),
+            "capsule" => Some(&selection::capsule::Mod),
+            "half_space" => Some(&selection::half_space::Mod),
+            "convex_polytope" => Some(&selection::convex_polytope::Mod),
+            "frustum" => Some(&selection::frustum::Mod),
+            "rect" => Some(&selection::rect::Mod),
+            "lasso" => Some(&selection::lasso::Mod),
+            "brush" => Some(&selection::brush::Mod),
+            "opacity_range" => Some(&selection::opacity_range::Mod),
+            "scale_range" => Some(&selection::scale_range::Mod),
+            "anisotropy" => Some(&selection::anisotropy::Mod),
+            "color_range" => Some(&selection::color_range::Mod),
+            "view_color_range" => Some(&selection::view_color_range::Mod),
+            "indices" => Some(&selection::indices::Mod),
+            "index_range" => Some(&selection::index_range::Mod),
+            "random" => Some(&selection::random::Mod),
+            "depth_range" => Some(&selection::depth_range::Mod),
+            "ray_pick" => Some(&selection::ray_pick::Mod),
+            "ray_pick_reduce" => Some(&selection::ray_pick_reduce::Mod),
+            "mesh" => Some(&selection::mesh::Mod),
+            "sdf" => Some(&selection::sdf::Mod),
+            "mask" => Some(&selection::mask::Mod),
+            "multi_view_mask" => Some(&selection::multi_view_mask::Mod),
+            "visibility" => Some(&selection::visibility::Mod),
+            "visibility_depth" => Some(&selection::visibility_depth::Mod),
+            "outlier" => Some(&selection::outlier::Mod),
+            "outlier_distance" => Some(&selection::outlier_distance::Mod),
             _ => None,
         }
     }
 }
 
 macro_rules! submodule {
-    ($name:ident $(, $dir:literal)?) => {
+    ($name:ident as $wesl_name:ident $(, $dir:literal)?) => {
         paste::paste! {
             pub mod $name {
                 pub struct Mod;
 
                 impl wesl::PkgModule for Mod {
                     fn name(&self) -> &'static str {
-                        stringify!($name)
+                        stringify!($wesl_name)
                     }
 
                     fn source(&self) -> &'static str {
-                        include_str!(concat!("shader/", $($dir,)? stringify!($name), ".wesl"))
+                        include_str!(concat!("shader/", $($dir,)? stringify!($wesl_name), ".wesl"))
                     }
 
                     fn submodules(&self) -> &[&dyn wesl::PkgModule] {
@@ -55,12 +87,18 @@ macro_rules! submodule {
             }
         }
     };
+    ($name:ident $(, $dir:literal)?) => {
+        submodule!($name as $name $(, $dir)?);
+    };
 }
 
 pub mod selection {
     use super::*;
 
     macro_rules! selection_submodule {
+        ($name:ident as $wesl_name:ident) => {
+            submodule!($name as $wesl_name, "selection/");
+        };
         ($name:ident) => {
             submodule!($name, "selection/");
         };
@@ -78,8 +116,44 @@ pub mod selection {
         }
 
         fn submodules(&self) -> &[&dyn PkgModule] {
-            static SUBMODULES: &[&dyn PkgModule] =
-                &[&ops::Mod, &primitive_ops::Mod, &utils::Mod, &sphere::Mod];
+            static SUBMODULES: &[&dyn PkgModule] = &[
+                &ops::Mod,
+                &primitive_ops::Mod,
+                &utils::Mod,
+                &inclusion::Mod,
+                &color::Mod,
+                &sh::Mod,
+                &sphere::Mod,
+                &box_::Mod,
+                &cone::Mod,
+                &cylinder::Mod,
+                &capsule::Mod,
+                &half_space::Mod,
+                &convex_polytope::Mod,
+                &frustum::Mod,
+                &rect::Mod,
+                &lasso::Mod,
+                &brush::Mod,
+                &opacity_range::Mod,
+                &scale_range::Mod,
+                &anisotropy::Mod,
+                &color_range::Mod,
+                &view_color_range::Mod,
+                &indices::Mod,
+                &index_range::Mod,
+                &random::Mod,
+                &depth_range::Mod,
+                &ray_pick::Mod,
+                &ray_pick_reduce::Mod,
+                &mesh::Mod,
+                &sdf::Mod,
+                &mask::Mod,
+                &multi_view_mask::Mod,
+                &visibility::Mod,
+                &visibility_depth::Mod,
+                &outlier::Mod,
+                &outlier_distance::Mod,
+            ];
             SUBMODULES
         }
 
@@ -88,7 +162,39 @@ pub mod selection {
                 "ops" => Some(&ops::Mod),
                 "primitive_ops" => Some(&primitive_ops::Mod),
                 "utils" => Some(&utils::Mod),
+                "inclusion" => Some(&inclusion::Mod),
+                "color" => Some(&color::Mod),
+                "sh" => Some(&sh::Mod),
                 "sphere" => Some(&sphere::Mod),
+                "box" => Some(&box_::Mod),
+                "cone" => Some(&cone::Mod),
+                "cylinder" => Some(&cylinder::Mod),
+                "capsule" => Some(&capsule::Mod),
+                "half_space" => Some(&half_space::Mod),
+                "convex_polytope" => Some(&convex_polytope::Mod),
+                "frustum" => Some(&frustum::Mod),
+                "rect" => Some(&rect::Mod),
+                "lasso" => Some(&lasso::Mod),
+                "brush" => Some(&brush::Mod),
+                "opacity_range" => Some(&opacity_range::Mod),
+                "scale_range" => Some(&scale_range::Mod),
+                "anisotropy" => Some(&anisotropy::Mod),
+                "color_range" => Some(&color_range::Mod),
+                "view_color_range" => Some(&view_color_range::Mod),
+                "indices" => Some(&indices::Mod),
+                "index_range" => Some(&index_range::Mod),
+                "random" => Some(&random::Mod),
+                "depth_range" => Some(&depth_range::Mod),
+                "ray_pick" => Some(&ray_pick::Mod),
+                "ray_pick_reduce" => Some(&ray_pick_reduce::Mod),
+                "mesh" => Some(&mesh::Mod),
+                "sdf" => Some(&sdf::Mod),
+                "mask" => Some(&mask::Mod),
+                "multi_view_mask" => Some(&multi_view_mask::Mod),
+                "visibility" => Some(&visibility::Mod),
+                "visibility_depth" => Some(&visibility_depth::Mod),
+                "outlier" => Some(&outlier::Mod),
+                "outlier_distance" => Some(&outlier_distance::Mod),
                 _ => None,
             }
         }
@@ -97,5 +203,37 @@ pub mod selection {
     selection_submodule!(ops);
     selection_submodule!(primitive_ops);
     selection_submodule!(utils);
+    selection_submodule!(inclusion);
+    selection_submodule!(color);
+    selection_submodule!(sh);
     selection_submodule!(sphere);
+    selection_submodule!(box_ as box);
+    selection_submodule!(cone);
+    selection_submodule!(cylinder);
+    selection_submodule!(capsule);
+    selection_submodule!(half_space);
+    selection_submodule!(convex_polytope);
+    selection_submodule!(frustum);
+    selection_submodule!(rect);
+    selection_submodule!(lasso);
+    selection_submodule!(brush);
+    selection_submodule!(opacity_range);
+    selection_submodule!(scale_range);
+    selection_submodule!(anisotropy);
+    selection_submodule!(color_range);
+    selection_submodule!(view_color_range);
+    selection_submodule!(indices);
+    selection_submodule!(index_range);
+    selection_submodule!(random);
+    selection_submodule!(depth_range);
+    selection_submodule!(ray_pick);
+    selection_submodule!(ray_pick_reduce);
+    selection_submodule!(mesh);
+    selection_submodule!(sdf);
+    selection_submodule!(mask);
+    selection_submodule!(multi_view_mask);
+    selection_submodule!(visibility);
+    selection_submodule!(visibility_depth);
+    selection_submodule!(outlier);
+    selection_submodule!(outlier_distance);
 }
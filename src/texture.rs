@@ -0,0 +1,446 @@
+use glam::*;
+use wgpu::util::DeviceExt;
+
+use crate::{
+    Error, MaskSelectionBuffer, MultiViewMaskSelectionBuffer, SdfSelectionBuffer,
+    core::BufferWrapper,
+};
+
+/// The signed distance field 3D texture for [`ops::sdf`](crate::ops::sdf).
+///
+/// Each texel is a signed distance in [`wgpu::TextureFormat::R32Float`], which is negative inside
+/// the volume. The texels are laid out in x, then y, then z order.
+#[derive(Debug, Clone)]
+pub struct SdfSelectionTexture {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl SdfSelectionTexture {
+    /// The format of the texture.
+    pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R32Float;
+
+    /// Create a new SDF selection texture with the distances.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: UVec3,
+        distances: &[f32],
+    ) -> Result<Self, Error> {
+        check_texel_count(distances.len(), size.element_product() as usize)?;
+
+        let texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("SDF Selection Texture"),
+                size: wgpu::Extent3d {
+                    width: size.x,
+                    height: size.y,
+                    depth_or_array_layers: size.z,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D3,
+                format: Self::FORMAT,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            bytemuck::cast_slice(distances),
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Ok(Self { texture, view })
+    }
+
+    /// Get the texture.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// Get the texture view.
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// Get the size of the texture.
+    pub fn size(&self) -> UVec3 {
+        let size = self.texture.size();
+        UVec3::new(size.width, size.height, size.depth_or_array_layers)
+    }
+
+    /// Update the distances of the SDF selection texture.
+    pub fn update(&self, queue: &wgpu::Queue, distances: &[f32]) -> Result<(), Error> {
+        let size = self.size();
+        check_texel_count(distances.len(), size.element_product() as usize)?;
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(distances),
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(size.x * std::mem::size_of::<f32>() as u32),
+                rows_per_image: Some(size.y),
+            },
+            self.texture.size(),
+        );
+
+        Ok(())
+    }
+
+    /// Create bind group 1 of [`ops::sdf`](crate::ops::sdf) with its bind group `layout`.
+    pub fn create_bind_group(
+        &self,
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        buffer: &SdfSelectionBuffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("SDF Selection Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.buffer().as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&self.view),
+                },
+            ],
+        })
+    }
+}
+
+/// The image mask 2D texture for [`ops::mask`](crate::ops::mask).
+///
+/// Each texel is a mask value in [`wgpu::TextureFormat::R8Unorm`], e.g. 255 for the pixels of a
+/// segmentation and 0 otherwise. The texels are laid out in rows from the top left corner.
+#[derive(Debug, Clone)]
+pub struct MaskSelectionTexture {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl MaskSelectionTexture {
+    /// The format of the texture.
+    pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
+
+    /// Create a new image mask selection texture with the mask values.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: UVec2,
+        mask: &[u8],
+    ) -> Result<Self, Error> {
+        check_texel_count(mask.len(), size.element_product() as usize)?;
+
+        let texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Mask Selection Texture"),
+                size: wgpu::Extent3d {
+                    width: size.x,
+                    height: size.y,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: Self::FORMAT,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            mask,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Ok(Self { texture, view })
+    }
+
+    /// Get the texture.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// Get the texture view.
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// Get the size of the texture.
+    pub fn size(&self) -> UVec2 {
+        let size = self.texture.size();
+        UVec2::new(size.width, size.height)
+    }
+
+    /// Update the mask values of the image mask selection texture.
+    pub fn update(&self, queue: &wgpu::Queue, mask: &[u8]) -> Result<(), Error> {
+        let size = self.size();
+        check_texel_count(mask.len(), size.element_product() as usize)?;
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            mask,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(size.x),
+                rows_per_image: Some(size.y),
+            },
+            self.texture.size(),
+        );
+
+        Ok(())
+    }
+
+    /// Create bind group 1 of [`ops::mask`](crate::ops::mask) with its bind group `layout`.
+    pub fn create_bind_group(
+        &self,
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        buffer: &MaskSelectionBuffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Mask Selection Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.buffer().as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&self.view),
+                },
+            ],
+        })
+    }
+}
+
+/// The multi-view image mask 2D texture array for
+/// [`ops::multi_view_mask`](crate::ops::multi_view_mask).
+///
+/// Each layer is the mask of a view in [`MultiViewMaskSelectionBuffer`], with the same texel
+/// layout as [`MaskSelectionTexture`]. The layers are laid out one after another.
+#[derive(Debug, Clone)]
+pub struct MultiViewMaskSelectionTexture {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+}
+
+impl MultiViewMaskSelectionTexture {
+    /// The format of the texture.
+    pub const FORMAT: wgpu::TextureFormat = MaskSelectionTexture::FORMAT;
+
+    /// Create a new multi-view image mask selection texture with the mask values.
+    ///
+    /// `size` is the size of each mask, and the number of layers is that of `masks`.
+    ///
+    /// Returns [`Error::NoMasks`] if `masks` is empty.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: UVec2,
+        masks: &[&[u8]],
+    ) -> Result<Self, Error> {
+        let texels = Self::concat_masks(size, masks)?;
+
+        let texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Multi-View Mask Selection Texture"),
+                size: wgpu::Extent3d {
+                    width: size.x,
+                    height: size.y,
+                    depth_or_array_layers: masks.len() as u32,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: Self::FORMAT,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &texels,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            ..Default::default()
+        });
+
+        Ok(Self { texture, view })
+    }
+
+    /// Get the texture.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// Get the texture view.
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// Get the size of each mask.
+    pub fn size(&self) -> UVec2 {
+        let size = self.texture.size();
+        UVec2::new(size.width, size.height)
+    }
+
+    /// Get the number of layers.
+    pub fn layer_count(&self) -> u32 {
+        self.texture.size().depth_or_array_layers
+    }
+
+    /// Update the mask values of all the layers.
+    pub fn update(&self, queue: &wgpu::Queue, masks: &[&[u8]]) -> Result<(), Error> {
+        check_layer_count(masks.len(), self.layer_count() as usize)?;
+
+        let size = self.size();
+        let texels = Self::concat_masks(size, masks)?;
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &texels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(size.x),
+                rows_per_image: Some(size.y),
+            },
+            self.texture.size(),
+        );
+
+        Ok(())
+    }
+
+    /// Create bind group 1 of [`ops::multi_view_mask`](crate::ops::multi_view_mask) with its bind
+    /// group `layout`.
+    pub fn create_bind_group(
+        &self,
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        buffer: &MultiViewMaskSelectionBuffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Multi-View Mask Selection Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.buffer().as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&self.view),
+                },
+            ],
+        })
+    }
+
+    /// Concatenate the masks into the texels of the layers.
+    fn concat_masks(size: UVec2, masks: &[&[u8]]) -> Result<Vec<u8>, Error> {
+        if masks.is_empty() {
+            return Err(Error::NoMasks);
+        }
+
+        let mask_len = size.element_product() as usize;
+        let mut texels = Vec::with_capacity(mask_len * masks.len());
+        for mask in masks {
+            check_texel_count(mask.len(), mask_len)?;
+            texels.extend_from_slice(mask);
+        }
+
+        Ok(texels)
+    }
+}
+
+/// Check the number of texels matches the size of the texture.
+fn check_texel_count(count: usize, expected: usize) -> Result<(), Error> {
+    if count != expected {
+        return Err(Error::TexelCountMismatch { count, expected });
+    }
+
+    Ok(())
+}
+
+/// Check the number of layers matches that of the texture.
+fn check_layer_count(count: usize, expected: usize) -> Result<(), Error> {
+    if count != expected {
+        return Err(Error::LayerCountMismatch { count, expected });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_texel_count_when_matches_should_be_ok() {
+        assert!(check_texel_count(24, 24).is_ok());
+    }
+
+    #[test]
+    fn test_check_texel_count_when_mismatches_should_return_error() {
+        assert!(matches!(
+            check_texel_count(23, 24),
+            Err(Error::TexelCountMismatch {
+                count: 23,
+                expected: 24
+            })
+        ));
+    }
+
+    #[test]
+    fn test_check_layer_count_when_matches_should_be_ok() {
+        assert!(check_layer_count(3, 3).is_ok());
+    }
+
+    #[test]
+    fn test_check_layer_count_when_mismatches_should_return_error() {
+        assert!(matches!(
+            check_layer_count(0, 1),
+            Err(Error::LayerCountMismatch {
+                count: 0,
+                expected: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_multi_view_mask_selection_texture_concat_masks_when_empty_should_return_error() {
+        assert!(matches!(
+            MultiViewMaskSelectionTexture::concat_masks(UVec2::new(2, 2), &[]),
+            Err(Error::NoMasks)
+        ));
+    }
+
+    #[test]
+    fn test_multi_view_mask_selection_texture_concat_masks_should_concat_layers() {
+        let texels =
+            MultiViewMaskSelectionTexture::concat_masks(UVec2::new(2, 1), &[&[1, 2], &[3, 4]])
+                .unwrap();
+        assert_eq!(texels, vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    VisibilityBuffer, VisibilityDepthBuffer,
+    core::{
+        self, BufferWrapper, ComputeBundle, ComputeBundleBuilder, GaussianPod,
+        GaussianTransformBuffer, GaussiansBuffer, ModelTransformBuffer,
+    },
+    shader,
+};
+
+/// A bundle for the depth pre-pass of the visibility from a camera.
+///
+/// The pre-pass finds the depth at which the accumulated front to back alpha of the Gaussians
+/// reaches the threshold for each cell of the viewport, in two passes:
+///
+/// 1. The optical depth `-ln(1 - alpha)` of the Gaussians is splatted over their projected
+///    k-sigma ellipses into logarithmically spaced depth bins with `atomicAdd`, where alpha is
+///    the opacity with the falloff of the Gaussian. The sum does not depend on the order, so the
+///    Gaussians do not have to be sorted.
+/// 2. The bins of each cell are accumulated front to back until the alpha reaches the threshold.
+///
+/// The result is written to a [`VisibilityDepthBuffer`], which is used by
+/// [`ops::visibility`](crate::ops::visibility) to select the visible Gaussians, e.g. to
+/// intersect with [`ops::rect`](crate::ops::rect) or [`ops::lasso`](crate::ops::lasso) so that
+/// they do not select through walls. The depth is only as precise as the bins, so the Gaussians
+/// in the same bin as the occluding surface are still visible.
+///
+/// ```wgsl
+/// import wgpu_3dgs_core::{
+///     gaussian::Gaussian,
+///     gaussian_transform::GaussianTransform,
+///     model_transform::ModelTransform,
+/// };
+///
+/// @group(0) @binding(0)
+/// var<uniform> visibility: Visibility;
+///
+/// @group(0) @binding(1)
+/// var<storage, read_write> depth: VisibilityDepth;
+///
+/// @group(0) @binding(2)
+/// var<uniform> model_transform: ModelTransform;
+///
+/// @group(0) @binding(3)
+/// var<uniform> gaussian_transform: GaussianTransform;
+///
+/// @group(0) @binding(4)
+/// var<storage, read> gaussians: array<Gaussian>;
+/// ```
+#[derive(Debug)]
+pub struct VisibilityBundle {
+    /// The compute bundle for splatting the optical depths.
+    pub splat_bundle: ComputeBundle<()>,
+    /// The compute bundle for resolving the occluded depths.
+    pub resolve_bundle: ComputeBundle<()>,
+}
+
+impl VisibilityBundle {
+    /// The depth bind group layout descriptor of both passes.
+    ///
+    /// This is different from
+    /// [`ops::VISIBILITY_BIND_GROUP_LAYOUT_DESCRIPTOR`](crate::ops::VISIBILITY_BIND_GROUP_LAYOUT_DESCRIPTOR),
+    /// which is for selecting the visible Gaussians.
+    pub const DEPTH_BIND_GROUP_LAYOUT_DESCRIPTOR: wgpu::BindGroupLayoutDescriptor<'static> =
+        wgpu::BindGroupLayoutDescriptor {
+            label: Some("Visibility Bind Group Layout"),
+            entries: &[
+                // Visibility uniform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Depth storage buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Model transform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Gaussian transform buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // Gaussian buffer
+                wgpu::BindGroupLayoutEntry {
+                    binding: 4,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+    /// Create a new visibility bundle.
+    pub fn new<G: GaussianPod>(device: &wgpu::Device) -> Self {
+        let splat_bundle = Self::create_bundle::<G>(device, "Visibility Splat", "splat_main");
+        let resolve_bundle = Self::create_bundle::<G>(device, "Visibility Resolve", "resolve_main");
+
+        Self {
+            splat_bundle,
+            resolve_bundle,
+        }
+    }
+
+    /// Get the depth bind group layout.
+    pub fn depth_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.splat_bundle.bind_group_layouts()[0]
+    }
+
+    /// Create the depth bind group.
+    ///
+    /// Both passes have the same layout, so the bind group is shared by them.
+    pub fn create_bind_group<G: GaussianPod>(
+        &self,
+        device: &wgpu::Device,
+        visibility: &VisibilityBuffer,
+        depth: &VisibilityDepthBuffer,
+        model_transform: &ModelTransformBuffer,
+        gaussian_transform: &GaussianTransformBuffer,
+        gaussians: &GaussiansBuffer<G>,
+    ) -> VisibilityDepthBindGroup {
+        let bind_group = self
+            .splat_bundle
+            .create_bind_group(
+                device,
+                0,
+                [
+                    visibility as &dyn BufferWrapper,
+                    depth as &dyn BufferWrapper,
+                    model_transform as &dyn BufferWrapper,
+                    gaussian_transform as &dyn BufferWrapper,
+                    gaussians as &dyn BufferWrapper,
+                ],
+            )
+            .expect("depth bind group");
+
+        VisibilityDepthBindGroup {
+            bind_group,
+            depth: depth.clone(),
+        }
+    }
+
+    /// Render the depths at which the accumulated alpha reaches the threshold.
+    ///
+    /// The depth bins are cleared before rendering, so this has to be recorded before the
+    /// [`ops::visibility`](crate::ops::visibility) operation is evaluated whenever the camera or
+    /// the Gaussians change.
+    pub fn render_depth(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        gaussian_count: u32,
+        bind_group: &VisibilityDepthBindGroup,
+    ) {
+        let depth = bind_group.depth();
+        encoder.clear_buffer(
+            depth.buffer(),
+            VisibilityDepthBuffer::HEADER_SIZE,
+            Some(depth.bins_size()),
+        );
+
+        self.splat_bundle
+            .dispatch(encoder, gaussian_count, [bind_group.bind_group()]);
+        self.resolve_bundle.dispatch(
+            encoder,
+            depth.size().element_product(),
+            [bind_group.bind_group()],
+        );
+    }
+
+    /// Create a visibility pass [`ComputeBundle`].
+    fn create_bundle<G: GaussianPod>(
+        device: &wgpu::Device,
+        label: &str,
+        entry_point: &str,
+    ) -> ComputeBundle<()> {
+        let mut resolver = wesl::PkgResolver::new();
+        resolver.add_package(&core::shader::Mod);
+        resolver.add_package(&shader::Mod);
+
+        ComputeBundleBuilder::new()
+            .label(label)
+            .bind_group(&Self::DEPTH_BIND_GROUP_LAYOUT_DESCRIPTOR)
+            .main_shader(package_module_path!(
+                wgpu_3dgs_editor::selection::visibility_depth
+            ))
+            .entry_point(entry_point)
+            .compile_options(wesl::CompileOptions {
+                features: G::features_map(),
+                ..Default::default()
+            })
+            .resolver(resolver)
+            .build_without_bind_groups(device)
+            .map_err(|e| log::error!("{e}"))
+            .expect("visibility bundle")
+    }
+}
+
+/// The depth bind group of [`VisibilityBundle`].
+///
+/// The [`VisibilityDepthBuffer`] bound is kept with it, so that
+/// [`VisibilityBundle::render_depth`] clears the bins that the passes accumulate into.
+#[derive(Debug)]
+pub struct VisibilityDepthBindGroup {
+    bind_group: wgpu::BindGroup,
+    depth: VisibilityDepthBuffer,
+}
+
+impl VisibilityDepthBindGroup {
+    /// Get the bind group.
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// Get the depth buffer.
+    pub fn depth(&self) -> &VisibilityDepthBuffer {
+        &self.depth
+    }
+}